@@ -0,0 +1,60 @@
+//! Generates an endgame tablebase of the 3x3 game and saves it.
+//!
+//! `cargo run --release -p bot --example generate_tablebase -- <fields> <games> <path>`
+//! plays `games` random games until at most `fields` fields are left
+//! uncollapsed and stores every position reachable from them with at most
+//! that many fields uncollapsed, 4, 100 and `tablebase.bin` by default.
+
+use bot::tablebase::{count_uncollapsed, Tablebase};
+use engine::game::Game;
+use rand::rngs::StdRng;
+use rand::seq::IndexedRandom;
+use rand::SeedableRng;
+use std::env;
+use std::path::PathBuf;
+
+const DEFAULT_UNCOLLAPSED_FIELDS: usize = 4;
+const DEFAULT_GAMES: u64 = 100;
+const DEFAULT_PATH: &str = "tablebase.bin";
+
+fn main() {
+    let mut args = env::args().skip(1);
+    let uncollapsed_fields = args.next().map_or(DEFAULT_UNCOLLAPSED_FIELDS, |fields| {
+        fields.parse().expect("Fields should be a number")
+    });
+    let games = args.next().map_or(DEFAULT_GAMES, |games| {
+        games.parse().expect("Games should be a number")
+    });
+    let path = PathBuf::from(args.next().unwrap_or_else(|| DEFAULT_PATH.to_string()));
+
+    let mut tablebase = Tablebase::new(3, 3);
+    for seed in 0..games {
+        if let Some(game) = late_game(uncollapsed_fields, seed) {
+            tablebase
+                .generate(&game, uncollapsed_fields)
+                .expect("Tablebase should be for the game");
+        }
+    }
+    tablebase.save(&path).expect("Tablebase should be saved");
+    println!("{} positions saved to {}", tablebase.len(), path.display());
+}
+
+/// Game played with random moves until at most `uncollapsed_fields` fields
+/// are left uncollapsed, None if it ends before.
+fn late_game(uncollapsed_fields: usize, seed: u64) -> Option<Game> {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut game = Game::new(3);
+    loop {
+        if game.get_status().is_game_end() {
+            return None;
+        }
+        if count_uncollapsed(&game) <= uncollapsed_fields {
+            return Some(game);
+        }
+        let legal_moves = game.legal_moves().collect::<Vec<_>>();
+        let player_move = *legal_moves.choose(&mut rng)?;
+        let player_symbol = game.get_status().get_player_turn();
+        game.player_move(player_move, player_symbol)
+            .expect("Legal move should be valid");
+    }
+}
@@ -0,0 +1,26 @@
+//! Solves the start position of the 3x3 game.
+//!
+//! Under perfect play X wins by half a point, the start position is worth 1.
+//! The solver stores about 44 million positions on the way, so run it with
+//! `cargo run --release -p bot --example solve_start`, it takes minutes.
+
+use bot::solver::{Outcome, Solver};
+use engine::game::Game;
+use engine::player_symbol::PlayerSymbol;
+
+const START_VALUE: i32 = 1;
+
+fn main() {
+    let mut solver = Solver::new();
+    let solution = solver
+        .solve(&Game::new(3))
+        .expect("Start position should have moves");
+    println!(
+        "Value {}, best move {:?}, {} positions",
+        solution.value(),
+        solution.best_move(),
+        solver.positions()
+    );
+    assert_eq!(solution.value(), START_VALUE);
+    assert_eq!(solution.outcome(PlayerSymbol::X), Outcome::Win);
+}
@@ -0,0 +1,93 @@
+use engine::board::Board;
+use engine::field_coordinate::FieldCoordinate;
+use engine::game::Game;
+use engine::player_symbol::PlayerSymbol;
+use engine::score::Score;
+
+/// Value of a won game, kept far above any heuristic value.
+const WIN_VALUE: i32 = 100_000;
+const COLLAPSED_WEIGHT: i32 = 8;
+
+/// Value of an ended game from the point of view of X, the half points of X
+/// minus the half points of O. Searches stopping at a depth weigh it with the
+/// depth left: games ended with more of it are reached sooner, so wins get a
+/// higher and losses a lower value, far above any heuristic value.
+pub(crate) fn score_value(score: &Score, depth: Option<usize>) -> i32 {
+    let half_points = |player_symbol| {
+        i32::try_from(score.half_points(player_symbol)).expect("Score should fit in i32")
+    };
+    let difference = half_points(PlayerSymbol::X) - half_points(PlayerSymbol::O);
+    match depth {
+        Some(depth) => {
+            difference * (WIN_VALUE + i32::try_from(depth).expect("Depth should fit in i32"))
+        }
+        None => difference,
+    }
+}
+
+/// Heuristic value of an unfinished game from the point of view of X.
+///
+/// Every segment of `win_length` fields that the opponent hasn't collapsed
+/// on counts for a player, more with each collapsed field and a little with
+/// each field holding one of their spooky marks. Under rules where lines
+/// lose, the segments count against the player instead.
+pub(crate) fn evaluate(game: &Game) -> i32 {
+    let board = game.get_board();
+    let value = lines(board.get_size(), board.get_win_length())
+        .iter()
+        .map(|line| line_value(board, line))
+        .sum::<i32>();
+    if game.get_rules().rewards_lines() {
+        value
+    } else {
+        -value
+    }
+}
+
+fn line_value(board: &Board, line: &[(usize, usize)]) -> i32 {
+    let (mut collapsed_x, mut collapsed_o, mut spooky_x, mut spooky_o) = (0, 0, 0, 0);
+    for &(row, column) in line {
+        let field_coordinate = FieldCoordinate { x: column, y: row };
+        match board.get_collapsed(field_coordinate) {
+            Some((PlayerSymbol::X, _)) => collapsed_x += 1,
+            Some((PlayerSymbol::O, _)) => collapsed_o += 1,
+            None => {
+                spooky_x += i32::from(board.has_mark(field_coordinate, PlayerSymbol::X));
+                spooky_o += i32::from(board.has_mark(field_coordinate, PlayerSymbol::O));
+            }
+        }
+    }
+    match (collapsed_x, collapsed_o) {
+        (_, 0) if collapsed_x > 0 => COLLAPSED_WEIGHT * collapsed_x * collapsed_x + spooky_x,
+        (0, _) if collapsed_o > 0 => -COLLAPSED_WEIGHT * collapsed_o * collapsed_o - spooky_o,
+        (0, 0) => spooky_x - spooky_o,
+        _ => 0,
+    }
+}
+
+/// Every segment of `win_length` fields in a row, column or diagonal, as
+/// `(row, column)` indexes.
+fn lines(size: usize, win_length: usize) -> Vec<Vec<(usize, usize)>> {
+    if win_length == 0 || win_length > size {
+        return Vec::new();
+    }
+    let starts = size - win_length + 1;
+    let mut lines = Vec::new();
+    for row in 0..size {
+        for column in 0..starts {
+            lines.push((0..win_length).map(|i| (row, column + i)).collect());
+            lines.push((0..win_length).map(|i| (column + i, row)).collect());
+        }
+    }
+    for row in 0..starts {
+        for column in 0..starts {
+            lines.push((0..win_length).map(|i| (row + i, column + i)).collect());
+            lines.push(
+                (0..win_length)
+                    .map(|i| (row + i, column + win_length - 1 - i))
+                    .collect(),
+            );
+        }
+    }
+    lines
+}
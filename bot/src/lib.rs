@@ -0,0 +1,7 @@
+mod evaluation;
+pub mod minimax;
+pub mod monte_carlo;
+pub mod solver;
+pub mod tablebase;
+#[cfg(test)]
+mod test_util;
@@ -0,0 +1,156 @@
+#[cfg(test)]
+mod test;
+
+use crate::evaluation::{evaluate, score_value};
+use engine::game::game_result::GameResult;
+use engine::game::Game;
+use engine::player_move::Move;
+use engine::player_symbol::PlayerSymbol;
+use std::time::{Duration, Instant};
+
+/// Player searching the game tree with minimax and alpha-beta pruning.
+///
+/// Mark, collapse and classical moves are all searched as plies of the
+/// player on turn. Positions left unfinished at the search depth are
+/// valued with a heuristic.
+#[derive(Debug, Clone)]
+pub struct Minimax {
+    depth: usize,
+    time_limit: Option<Duration>,
+}
+
+impl Minimax {
+    #[must_use]
+    pub fn new(depth: usize) -> Self {
+        Minimax {
+            depth,
+            time_limit: None,
+        }
+    }
+
+    /// Stops searching deeper once `time_limit` is spent and plays the best
+    /// move of the last completed depth.
+    #[must_use]
+    pub fn with_time_limit(mut self, time_limit: Duration) -> Self {
+        self.time_limit = Some(time_limit);
+        self
+    }
+
+    /// Picks a move for the player on turn. Searches one ply deeper at a
+    /// time until the depth or the time limit is reached.
+    /// Returns None if the game has ended.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if the best move found isn't among the legal moves.
+    #[must_use]
+    pub fn choose_move(&self, game: &Game) -> Option<Move> {
+        let mut search = Search {
+            game: game.clone(),
+            deadline: self
+                .time_limit
+                .map(|time_limit| Instant::now() + time_limit),
+        };
+        let mut moves = search.game.legal_moves().collect::<Vec<_>>();
+        let mut best_move = *moves.first()?;
+        for depth in 1..=self.depth.max(1) {
+            let Some(player_move) = search.best_move(&moves, depth) else {
+                break;
+            };
+            best_move = player_move;
+            // Searching the best move first prunes more in the next depth
+            let position = moves
+                .iter()
+                .position(|&player_move| player_move == best_move)
+                .expect("Best move should be legal");
+            moves[..=position].rotate_right(1);
+        }
+        Some(best_move)
+    }
+}
+
+struct Search {
+    game: Game,
+    deadline: Option<Instant>,
+}
+
+impl Search {
+    /// Returns None if the time ran out before every move was searched.
+    fn best_move(&mut self, moves: &[Move], depth: usize) -> Option<Move> {
+        let is_maximizing = self.is_maximizing();
+        let (mut alpha, mut beta) = (i32::MIN, i32::MAX);
+        let mut best: Option<(Move, i32)> = None;
+        for &player_move in moves {
+            let value = self.value_after(player_move, depth - 1, alpha, beta)?;
+            let is_better = best.is_none_or(|(_, best_value)| {
+                if is_maximizing {
+                    value > best_value
+                } else {
+                    value < best_value
+                }
+            });
+            if is_better {
+                best = Some((player_move, value));
+            }
+            if is_maximizing {
+                alpha = alpha.max(value);
+            } else {
+                beta = beta.min(value);
+            }
+        }
+        best.map(|(player_move, _)| player_move)
+    }
+
+    fn value(&mut self, depth: usize, mut alpha: i32, mut beta: i32) -> Option<i32> {
+        if self
+            .deadline
+            .is_some_and(|deadline| Instant::now() >= deadline)
+        {
+            return None;
+        }
+        let moves = self.game.legal_moves().collect::<Vec<_>>();
+        if depth == 0 || moves.is_empty() {
+            return Some(evaluate(&self.game));
+        }
+        let is_maximizing = self.is_maximizing();
+        let mut best_value = if is_maximizing { i32::MIN } else { i32::MAX };
+        for player_move in moves {
+            let value = self.value_after(player_move, depth - 1, alpha, beta)?;
+            if is_maximizing {
+                best_value = best_value.max(value);
+                alpha = alpha.max(value);
+            } else {
+                best_value = best_value.min(value);
+                beta = beta.min(value);
+            }
+            if alpha >= beta {
+                break;
+            }
+        }
+        Some(best_value)
+    }
+
+    fn value_after(
+        &mut self,
+        player_move: Move,
+        depth: usize,
+        alpha: i32,
+        beta: i32,
+    ) -> Option<i32> {
+        let player_symbol = self.game.get_status().get_player_turn();
+        let result = self
+            .game
+            .player_move(player_move, player_symbol)
+            .expect("Legal move should be valid");
+        let value = match result {
+            GameResult::GameEnd(score, _) => Some(score_value(&score, Some(depth))),
+            _ => self.value(depth, alpha, beta),
+        };
+        self.game.undo().expect("Move should be undone");
+        value
+    }
+
+    fn is_maximizing(&self) -> bool {
+        self.game.get_status().get_player_turn() == PlayerSymbol::X
+    }
+}
@@ -0,0 +1,71 @@
+use super::*;
+use crate::test_util::{mark, x_to_collapse_for_row};
+use engine::rules::RuleSet;
+
+#[test]
+fn no_move_after_game_end() {
+    let mut game = Game::new(3);
+    let _ = game.end_game(None);
+    assert_eq!(Minimax::new(2).choose_move(&game), None);
+}
+
+#[test]
+fn first_move_is_mark() {
+    let game = Game::new(3);
+    assert!(matches!(
+        Minimax::new(2).choose_move(&game),
+        Some(Move::Mark { .. })
+    ));
+}
+
+#[test]
+fn winning_collapse() {
+    let mut game = x_to_collapse_for_row();
+    let player_move = Minimax::new(1).choose_move(&game).unwrap();
+    let result = game.player_move(player_move, PlayerSymbol::X).unwrap();
+    let GameResult::GameEnd(score, _) = result else {
+        panic!("Game should end");
+    };
+    assert_eq!(score.winner(), Some(PlayerSymbol::X));
+}
+
+#[test]
+fn winning_collapse_with_deeper_search() {
+    let mut game = x_to_collapse_for_row();
+    let player_move = Minimax::new(3).choose_move(&game).unwrap();
+    let result = game.player_move(player_move, PlayerSymbol::X).unwrap();
+    let GameResult::GameEnd(score, _) = result else {
+        panic!("Game should end");
+    };
+    assert_eq!(score.winner(), Some(PlayerSymbol::X));
+}
+
+#[test]
+fn misere_evaluation() {
+    let mut game = Game::new(3);
+    game.player_move(mark((0, 0), (1, 1)), PlayerSymbol::X)
+        .unwrap();
+    let value = evaluate(&game);
+    assert!(value > 0);
+    assert_eq!(evaluate(&game.with_rule_set(RuleSet::Misere)), -value);
+}
+
+#[test]
+fn move_without_time() {
+    let game = Game::new(3);
+    let player_move = Minimax::new(5)
+        .with_time_limit(Duration::ZERO)
+        .choose_move(&game);
+    assert!(player_move.is_some_and(|player_move| game.legal_moves().any(|m| m == player_move)));
+}
+
+#[test]
+fn self_play_until_end() {
+    let mut game = Game::new(3);
+    let minimax = Minimax::new(2);
+    while let Some(player_move) = minimax.choose_move(&game) {
+        let player_symbol = game.get_status().get_player_turn();
+        game.player_move(player_move, player_symbol).unwrap();
+    }
+    assert!(game.get_status().is_game_end());
+}
@@ -0,0 +1,243 @@
+#[cfg(test)]
+mod test;
+
+use crate::evaluation::{evaluate, score_value};
+use engine::game::game_result::GameResult;
+use engine::game::Game;
+use engine::player_move::Move;
+use engine::player_symbol::PlayerSymbol;
+use engine::score::Score;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::time::{Duration, Instant};
+
+const EXPLORATION: f64 = std::f64::consts::SQRT_2;
+
+/// Player running Monte Carlo tree search.
+///
+/// Mark, collapse and classical moves are all edges of the tree. Rollouts
+/// play random marks, while collapses are chosen greedily for the player
+/// collapsing. The same seed and iteration count always give the same move.
+#[derive(Debug, Clone)]
+pub struct MonteCarlo {
+    iterations: usize,
+    time_limit: Option<Duration>,
+    seed: u64,
+}
+
+impl MonteCarlo {
+    #[must_use]
+    pub fn new(iterations: usize, seed: u64) -> Self {
+        MonteCarlo {
+            iterations,
+            time_limit: None,
+            seed,
+        }
+    }
+
+    /// Stops the search once `time_limit` is spent, even if not every
+    /// iteration was run.
+    #[must_use]
+    pub fn with_time_limit(mut self, time_limit: Duration) -> Self {
+        self.time_limit = Some(time_limit);
+        self
+    }
+
+    /// Picks the most visited move for the player on turn.
+    /// Returns None if the game has ended.
+    #[must_use]
+    pub fn choose_move(&self, game: &Game) -> Option<Move> {
+        let mut search = Search {
+            game: game.clone(),
+            rng: StdRng::seed_from_u64(self.seed),
+            nodes: vec![Node::new(None, None, game.legal_moves().collect())],
+        };
+        if search.nodes[0].untried_moves.is_empty() {
+            return None;
+        }
+        let deadline = self
+            .time_limit
+            .map(|time_limit| Instant::now() + time_limit);
+        for _ in 0..self.iterations.max(1) {
+            search.iterate();
+            if deadline.is_some_and(|deadline| Instant::now() >= deadline) {
+                break;
+            }
+        }
+        search.nodes[0]
+            .children
+            .iter()
+            .map(|&child| &search.nodes[child])
+            .max_by_key(|node| node.visits)
+            .and_then(|node| node.player_move)
+    }
+}
+
+struct Node {
+    player_move: Option<Move>,
+    /// Player who made the move leading to this node.
+    player_symbol: Option<PlayerSymbol>,
+    /// Score of the game ended by the move leading to this node.
+    score: Option<Score>,
+    children: Vec<usize>,
+    untried_moves: Vec<Move>,
+    visits: u32,
+    reward: f64,
+}
+
+impl Node {
+    fn new(
+        player_move: Option<Move>,
+        player_symbol: Option<PlayerSymbol>,
+        untried_moves: Vec<Move>,
+    ) -> Self {
+        Node {
+            player_move,
+            player_symbol,
+            score: None,
+            children: Vec::new(),
+            untried_moves,
+            visits: 0,
+            reward: 0.0,
+        }
+    }
+
+    fn upper_confidence_bound(&self, parent_visits: u32) -> f64 {
+        let visits = f64::from(self.visits);
+        self.reward / visits + EXPLORATION * (f64::from(parent_visits).ln() / visits).sqrt()
+    }
+}
+
+struct Search {
+    game: Game,
+    rng: StdRng,
+    nodes: Vec<Node>,
+}
+
+impl Search {
+    /// Selects a leaf, expands it with one move, plays a rollout from it
+    /// and propagates the result back to the root.
+    fn iterate(&mut self) {
+        let mut path = vec![0];
+        let mut node = 0;
+        while self.nodes[node].untried_moves.is_empty() && !self.nodes[node].children.is_empty() {
+            let parent_visits = self.nodes[node].visits;
+            node = *self.nodes[node]
+                .children
+                .iter()
+                .max_by(|&&first, &&second| {
+                    self.nodes[first]
+                        .upper_confidence_bound(parent_visits)
+                        .total_cmp(&self.nodes[second].upper_confidence_bound(parent_visits))
+                })
+                .expect("Node should have children");
+            let player_move = self.nodes[node]
+                .player_move
+                .expect("Child should have a move");
+            self.make_move(player_move);
+            path.push(node);
+        }
+
+        let mut moves_made = path.len() - 1;
+        if !self.nodes[node].untried_moves.is_empty() {
+            node = self.expand(node);
+            path.push(node);
+            moves_made += 1;
+        }
+        let score = match self.nodes[node].score {
+            Some(score) => score,
+            None => self.rollout(&mut moves_made),
+        };
+
+        for _ in 0..moves_made {
+            self.game.undo().expect("Move should be undone");
+        }
+        for node in path {
+            let node = &mut self.nodes[node];
+            node.visits += 1;
+            if let Some(player_symbol) = node.player_symbol {
+                node.reward += reward(&score, player_symbol);
+            }
+        }
+    }
+
+    fn expand(&mut self, node: usize) -> usize {
+        let untried_moves = &mut self.nodes[node].untried_moves;
+        let index = self.rng.random_range(0..untried_moves.len());
+        let player_move = untried_moves.swap_remove(index);
+        let player_symbol = self.game.get_status().get_player_turn();
+        let result = self.make_move(player_move);
+        let mut child = Node::new(
+            Some(player_move),
+            Some(player_symbol),
+            self.game.legal_moves().collect(),
+        );
+        if let GameResult::GameEnd(score, _) = result {
+            child.score = Some(score);
+        }
+        self.nodes.push(child);
+        let child = self.nodes.len() - 1;
+        self.nodes[node].children.push(child);
+        child
+    }
+
+    /// Plays the game to its end and returns the final score. A game left
+    /// without moves by its rules counts as a draw.
+    fn rollout(&mut self, moves_made: &mut usize) -> Score {
+        loop {
+            let moves = self.game.legal_moves().collect::<Vec<_>>();
+            if moves.is_empty() {
+                return Score::default();
+            }
+            let player_move = if matches!(moves.first(), Some(Move::Collapse { .. })) {
+                self.best_collapse(&moves)
+            } else {
+                moves[self.rng.random_range(0..moves.len())]
+            };
+            let result = self.make_move(player_move);
+            *moves_made += 1;
+            if let GameResult::GameEnd(score, _) = result {
+                return score;
+            }
+        }
+    }
+
+    /// Collapse giving the best immediate value to the player collapsing.
+    fn best_collapse(&mut self, moves: &[Move]) -> Move {
+        let sign = match self.game.get_status().get_player_turn() {
+            PlayerSymbol::X => 1,
+            PlayerSymbol::O => -1,
+        };
+        *moves
+            .iter()
+            .max_by_key(|&&player_move| {
+                let value = match self.make_move(player_move) {
+                    GameResult::GameEnd(score, _) => score_value(&score, Some(0)),
+                    _ => evaluate(&self.game),
+                };
+                self.game.undo().expect("Move should be undone");
+                sign * value
+            })
+            .expect("Collapse should be possible")
+    }
+
+    fn make_move(&mut self, player_move: Move) -> GameResult {
+        let player_symbol = self.game.get_status().get_player_turn();
+        self.game
+            .player_move(player_move, player_symbol)
+            .expect("Legal move should be valid")
+    }
+}
+
+/// Share of the points won by the player, a draw is worth half.
+fn reward(score: &Score, player_symbol: PlayerSymbol) -> f64 {
+    let half_points = |player_symbol| {
+        f64::from(u32::try_from(score.half_points(player_symbol)).expect("Score should fit in u32"))
+    };
+    let total = half_points(PlayerSymbol::X) + half_points(PlayerSymbol::O);
+    if total == 0.0 {
+        0.5
+    } else {
+        half_points(player_symbol) / total
+    }
+}
@@ -0,0 +1,78 @@
+use super::*;
+use crate::test_util::x_to_collapse_for_row;
+use engine::game::game_result::EndReason;
+use engine::line::Line;
+use engine::rules::Rules;
+use std::sync::Arc;
+
+#[test]
+fn no_move_after_game_end() {
+    let mut game = Game::new(3);
+    let _ = game.end_game(None);
+    assert_eq!(MonteCarlo::new(100, 0).choose_move(&game), None);
+}
+
+#[test]
+fn same_seed_same_move() {
+    let game = Game::new(4);
+    let first = MonteCarlo::new(200, 7).choose_move(&game);
+    let second = MonteCarlo::new(200, 7).choose_move(&game);
+    assert!(first.is_some());
+    assert_eq!(first, second);
+}
+
+#[test]
+fn winning_collapse() {
+    let mut game = x_to_collapse_for_row();
+    let player_move = MonteCarlo::new(200, 0).choose_move(&game).unwrap();
+    let result = game.player_move(player_move, PlayerSymbol::X).unwrap();
+    let GameResult::GameEnd(score, _) = result else {
+        panic!("Game should end");
+    };
+    assert_eq!(score.winner(), Some(PlayerSymbol::X));
+}
+
+#[test]
+fn move_without_time() {
+    let game = Game::new(5);
+    let player_move = MonteCarlo::new(1_000_000, 0)
+        .with_time_limit(Duration::ZERO)
+        .choose_move(&game);
+    assert!(player_move.is_some_and(|player_move| game.legal_moves().any(|m| m == player_move)));
+}
+
+#[test]
+fn self_play_until_end_5x5() {
+    let mut game = Game::new(5);
+    let monte_carlo = MonteCarlo::new(20, 1);
+    while let Some(player_move) = monte_carlo.choose_move(&game) {
+        let player_symbol = game.get_status().get_player_turn();
+        game.player_move(player_move, player_symbol).unwrap();
+    }
+    assert!(game.get_status().is_game_end());
+}
+
+/// Ends no game and leaves the last field uncollapsed.
+struct NoEnd;
+
+impl Rules for NoEnd {
+    fn allows_classical(&self) -> bool {
+        false
+    }
+
+    fn end(&self, _lines: &[Line], _uncollapsed_fields: usize) -> Option<(Score, EndReason)> {
+        None
+    }
+}
+
+#[test]
+fn rollout_without_moves() {
+    let mut game = Game::with_rules(3, 3, Arc::new(NoEnd)).unwrap();
+    let monte_carlo = MonteCarlo::new(50, 3);
+    while let Some(player_move) = monte_carlo.choose_move(&game) {
+        let player_symbol = game.get_status().get_player_turn();
+        game.player_move(player_move, player_symbol).unwrap();
+    }
+    assert_eq!(game.legal_moves().count(), 0);
+    assert!(!game.get_status().is_game_end());
+}
@@ -0,0 +1,279 @@
+#[cfg(test)]
+mod test;
+
+use crate::evaluation::score_value;
+use engine::game::game_result::GameResult;
+use engine::game::Game;
+use engine::player_move::Move;
+use engine::player_symbol::PlayerSymbol;
+use engine::rules::{CollapseChooser, RuleSet};
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
+
+/// Value of a position under perfect play of both players with the move
+/// reaching it.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Solution {
+    value: i32,
+    best_move: Move,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Win,
+    Draw,
+    Loss,
+}
+
+impl Solution {
+    pub(crate) fn new(value: i32, best_move: Move) -> Self {
+        Solution { value, best_move }
+    }
+
+    /// Half points of X minus half points of O at the end of the game.
+    #[must_use]
+    pub fn value(&self) -> i32 {
+        self.value
+    }
+
+    #[must_use]
+    pub fn best_move(&self) -> Move {
+        self.best_move
+    }
+
+    /// Result of the game for the player under perfect play.
+    #[must_use]
+    pub fn outcome(&self, player_symbol: PlayerSymbol) -> Outcome {
+        let value = match player_symbol {
+            PlayerSymbol::X => self.value,
+            PlayerSymbol::O => -self.value,
+        };
+        match value.cmp(&0) {
+            Ordering::Greater => Outcome::Win,
+            Ordering::Equal => Outcome::Draw,
+            Ordering::Less => Outcome::Loss,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Bound {
+    Exact,
+    Lower,
+    Upper,
+}
+
+/// Board and rules the positions of a table were solved for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Configuration {
+    size: usize,
+    win_length: usize,
+    rule_set: Option<RuleSet>,
+    collapse_chooser: CollapseChooser,
+}
+
+impl Configuration {
+    fn of(game: &Game) -> Self {
+        let board = game.get_board();
+        Configuration {
+            size: board.get_size(),
+            win_length: board.get_win_length(),
+            rule_set: game.get_rule_set(),
+            collapse_chooser: game.get_collapse_chooser(),
+        }
+    }
+}
+
+/// Exhaustive search of the game tree with alpha-beta pruning.
+///
+/// Positions are stored by their hash up to rotations and reflections of
+/// the board, so each position is solved once for all its symmetric
+/// copies. The table is kept between calls, solving positions of the same
+/// game again is cheap. It's cleared for a game of another board, rule set
+/// or collapse chooser, and for every game played by rules not of a
+/// [`RuleSet`], which can't be told apart.
+#[derive(Debug, Default)]
+pub struct Solver {
+    table: HashMap<u64, (i32, Bound)>,
+    configuration: Option<Configuration>,
+}
+
+impl Solver {
+    #[must_use]
+    pub fn new() -> Self {
+        Solver::default()
+    }
+
+    /// Solves the position for the player on turn. Returns None if the game
+    /// has ended.
+    pub fn solve(&mut self, game: &Game) -> Option<Solution> {
+        let configuration = Configuration::of(game);
+        if self.configuration != Some(configuration) || configuration.rule_set.is_none() {
+            self.table.clear();
+            self.configuration = Some(configuration);
+        }
+        let mut game = game.clone();
+        let is_maximizing = is_maximizing(&game);
+        let mut best: Option<Solution> = None;
+        let (mut alpha, mut beta) = (i32::MIN, i32::MAX);
+        for player_move in game.legal_moves().collect::<Vec<_>>() {
+            let value = self.value_after(&mut game, player_move, alpha, beta);
+            let is_better = best.is_none_or(|best| {
+                if is_maximizing {
+                    value > best.value
+                } else {
+                    value < best.value
+                }
+            });
+            if is_better {
+                best = Some(Solution {
+                    value,
+                    best_move: player_move,
+                });
+            }
+            // Only a strictly better value is searched further, so the
+            // value of the best move is exact
+            if is_maximizing {
+                alpha = alpha.max(value);
+            } else {
+                beta = beta.min(value);
+            }
+        }
+        best
+    }
+
+    /// Number of positions stored, each standing for all its symmetric
+    /// copies.
+    #[must_use]
+    pub fn positions(&self) -> usize {
+        self.table.len()
+    }
+
+    fn value(&mut self, game: &mut Game, key: u64, mut alpha: i32, mut beta: i32) -> i32 {
+        if let Some(&(value, bound)) = self.table.get(&key) {
+            match bound {
+                Bound::Exact => return value,
+                Bound::Lower if value >= beta => return value,
+                Bound::Upper if value <= alpha => return value,
+                Bound::Lower | Bound::Upper => {}
+            }
+        }
+        let (original_alpha, original_beta) = (alpha, beta);
+        let is_maximizing = is_maximizing(game);
+        let mut best_value = if is_maximizing { i32::MIN } else { i32::MAX };
+        let legal_moves = game.legal_moves().collect::<Vec<_>>();
+        // A game that can't go on counts as a draw, as it ends without points
+        if legal_moves.is_empty() {
+            self.table.insert(key, (0, Bound::Exact));
+            return 0;
+        }
+        // Moves leading to symmetric copies of a searched position are skipped
+        let mut searched = HashSet::new();
+        for player_move in legal_moves {
+            let player_symbol = game.get_status().get_player_turn();
+            let result = game
+                .player_move(player_move, player_symbol)
+                .expect("Legal move should be valid");
+            let value = if let GameResult::GameEnd(score, _) = result {
+                Some(score_value(&score, None))
+            } else {
+                let (key, _) = game.canonical_hash();
+                searched
+                    .insert(key)
+                    .then(|| self.value(game, key, alpha, beta))
+            };
+            game.undo().expect("Move should be undone");
+            let Some(value) = value else {
+                continue;
+            };
+            if is_maximizing {
+                best_value = best_value.max(value);
+                alpha = alpha.max(value);
+            } else {
+                best_value = best_value.min(value);
+                beta = beta.min(value);
+            }
+            if alpha >= beta {
+                break;
+            }
+        }
+        let bound = if best_value <= original_alpha {
+            Bound::Upper
+        } else if best_value >= original_beta {
+            Bound::Lower
+        } else {
+            Bound::Exact
+        };
+        self.table.insert(key, (best_value, bound));
+        best_value
+    }
+
+    fn value_after(&mut self, game: &mut Game, player_move: Move, alpha: i32, beta: i32) -> i32 {
+        let player_symbol = game.get_status().get_player_turn();
+        let result = game
+            .player_move(player_move, player_symbol)
+            .expect("Legal move should be valid");
+        let value = if let GameResult::GameEnd(score, _) = result {
+            score_value(&score, None)
+        } else {
+            let (key, _) = game.canonical_hash();
+            self.value(game, key, alpha, beta)
+        };
+        game.undo().expect("Move should be undone");
+        value
+    }
+}
+
+/// Number of move sequences of each length from 1 to `depth` starting from
+/// the position. Games ending before a depth are not continued.
+#[must_use]
+pub fn perft(game: &Game, depth: usize) -> Vec<u64> {
+    let mut counts = vec![0; depth];
+    count_sequences(&mut game.clone(), &mut counts, 0);
+    counts
+}
+
+/// Number of distinct positions up to rotations and reflections of the
+/// board reached after each number of moves from 1 to `depth`.
+#[must_use]
+pub fn perft_unique(game: &Game, depth: usize) -> Vec<usize> {
+    let mut positions = vec![HashSet::new(); depth];
+    collect_positions(&mut game.clone(), &mut positions, 0);
+    positions.iter().map(HashSet::len).collect()
+}
+
+fn count_sequences(game: &mut Game, counts: &mut [u64], ply: usize) {
+    if ply == counts.len() {
+        return;
+    }
+    for player_move in game.legal_moves().collect::<Vec<_>>() {
+        counts[ply] += 1;
+        let player_symbol = game.get_status().get_player_turn();
+        game.player_move(player_move, player_symbol)
+            .expect("Legal move should be valid");
+        count_sequences(game, counts, ply + 1);
+        game.undo().expect("Move should be undone");
+    }
+}
+
+fn collect_positions(game: &mut Game, positions: &mut [HashSet<u64>], ply: usize) {
+    if ply == positions.len() {
+        return;
+    }
+    for player_move in game.legal_moves().collect::<Vec<_>>() {
+        let player_symbol = game.get_status().get_player_turn();
+        game.player_move(player_move, player_symbol)
+            .expect("Legal move should be valid");
+        let (key, _) = game.canonical_hash();
+        // The moves after a position don't depend on how it was reached
+        if positions[ply].insert(key) {
+            collect_positions(game, positions, ply + 1);
+        }
+        game.undo().expect("Move should be undone");
+    }
+}
+
+fn is_maximizing(game: &Game) -> bool {
+    game.get_status().get_player_turn() == PlayerSymbol::X
+}
@@ -0,0 +1,141 @@
+use super::*;
+use crate::test_util::{late_position, x_to_collapse_for_row};
+use engine::game::game_result::EndReason;
+use engine::line::Line;
+use engine::rules::{CollapseChooser, RuleSet, Rules};
+use engine::score::Score;
+use std::sync::Arc;
+
+#[test]
+fn perft_of_start_position() {
+    // Every pair of fields can be marked, marking the same pair twice closes
+    // a cycle of two fields collapsing in four ways
+    assert_eq!(perft(&Game::new(3), 3), vec![36, 1296, 1260 * 36 + 36 * 4]);
+}
+
+#[test]
+fn perft_unique_of_start_position() {
+    // Pairs of fields up to symmetry: corner-edge next to it, corner-edge
+    // far away, corner-center, opposite corners, adjacent corners, edge-edge
+    // across, adjacent edges, edge-center
+    assert_eq!(perft_unique(&Game::new(3), 1), vec![8]);
+    let unique = perft_unique(&Game::new(3), 3);
+    let all = perft(&Game::new(3), 3);
+    for (unique, all) in unique.iter().zip(all) {
+        assert!((*unique as u64) * 8 >= all);
+        assert!(*unique as u64 <= all);
+    }
+}
+
+#[test]
+fn no_solution_after_game_end() {
+    let mut game = Game::new(3);
+    let _ = game.end_game(None);
+    assert_eq!(Solver::new().solve(&game), None);
+}
+
+#[test]
+fn winning_collapse() {
+    let mut game = x_to_collapse_for_row();
+    let solution = Solver::new().solve(&game).unwrap();
+    assert_eq!(solution.value(), 2);
+    let result = game
+        .player_move(solution.best_move(), PlayerSymbol::X)
+        .unwrap();
+    assert!(matches!(result, GameResult::GameEnd(score, _) if score_value(&score, None) == 2));
+}
+
+#[test]
+fn solve_small_board_from_start() {
+    let mut solver = Solver::new();
+    let game = Game::with_win_length(2, 2).unwrap();
+    let solution = solver.solve(&game).unwrap();
+    assert_eq!(solution.value(), brute_force_value(&mut game.clone()));
+    assert!(solver.positions() > 0);
+}
+
+#[test]
+fn solve_agrees_with_brute_force() {
+    let mut solver = Solver::new();
+    let games = (0..20)
+        .filter_map(late_position)
+        .take(4)
+        .collect::<Vec<_>>();
+    assert!(!games.is_empty());
+    for mut game in games {
+        let solution = solver.solve(&game).unwrap();
+        assert_eq!(solution.value(), brute_force_value(&mut game.clone()));
+
+        let player_symbol = game.get_status().get_player_turn();
+        let value = match game
+            .player_move(solution.best_move(), player_symbol)
+            .unwrap()
+        {
+            GameResult::GameEnd(score, _) => score_value(&score, None),
+            _ => brute_force_value(&mut game),
+        };
+        assert_eq!(value, solution.value());
+    }
+}
+
+#[test]
+fn solve_games_of_other_rules() {
+    let mut solver = Solver::new();
+    let game = (0..20).find_map(late_position).unwrap();
+    let games = [
+        game.clone(),
+        game.clone().with_rule_set(RuleSet::Misere),
+        game.clone()
+            .with_rule_set(RuleSet::Misere)
+            .with_collapse_chooser(CollapseChooser::Creator),
+        game,
+    ];
+    for game in games {
+        assert_eq!(solver.solve(&game), Solver::new().solve(&game));
+    }
+}
+
+struct NoEnd;
+
+impl Rules for NoEnd {
+    fn allows_classical(&self) -> bool {
+        false
+    }
+
+    fn end(&self, _lines: &[Line], _uncollapsed_fields: usize) -> Option<(Score, EndReason)> {
+        None
+    }
+}
+
+#[test]
+fn positions_without_moves_are_draws() {
+    let mut solver = Solver::new();
+    let game = Game::with_rules(2, 2, Arc::new(NoEnd)).unwrap();
+    assert_eq!(solver.solve(&game).unwrap().value(), 0);
+    assert!(solver.positions() > 0);
+    assert!(solver.table.values().all(|&(value, _)| value == 0));
+}
+
+/// Minimax over the whole tree without pruning or stored positions.
+fn brute_force_value(game: &mut Game) -> i32 {
+    let is_maximizing = is_maximizing(game);
+    let values = game
+        .legal_moves()
+        .collect::<Vec<_>>()
+        .into_iter()
+        .map(|player_move| {
+            let player_symbol = game.get_status().get_player_turn();
+            let value = match game.player_move(player_move, player_symbol).unwrap() {
+                GameResult::GameEnd(score, _) => score_value(&score, None),
+                _ => brute_force_value(game),
+            };
+            game.undo().unwrap();
+            value
+        })
+        .collect::<Vec<_>>();
+    if is_maximizing {
+        values.into_iter().max().unwrap()
+    } else {
+        values.into_iter().min().unwrap()
+    }
+}
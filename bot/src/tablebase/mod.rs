@@ -0,0 +1,217 @@
+#[allow(clippy::module_name_repetitions)]
+pub mod tablebase_error;
+#[cfg(test)]
+mod test;
+
+use crate::solver::{Solution, Solver};
+use crate::tablebase::tablebase_error::TablebaseError;
+use engine::field_coordinate::FieldCoordinate;
+use engine::game::Game;
+use engine::rules::{CollapseChooser, RuleSet};
+use error_stack::{Report, Result, ResultExt};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Read, Write};
+use std::path::Path;
+
+/// Solved positions of one board size, win length and rule variant, stored
+/// by their hash up to rotations and reflections of the board.
+///
+/// Best moves are kept for the stored copy of a position and turned back on
+/// lookup, so one entry serves all symmetric copies.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Tablebase {
+    size: usize,
+    win_length: usize,
+    rule_set: RuleSet,
+    collapse_chooser: CollapseChooser,
+    entries: HashMap<u64, Solution>,
+}
+
+impl Tablebase {
+    #[must_use]
+    pub fn new(size: usize, win_length: usize) -> Self {
+        Tablebase {
+            size,
+            win_length,
+            rule_set: RuleSet::default(),
+            collapse_chooser: CollapseChooser::default(),
+            entries: HashMap::new(),
+        }
+    }
+
+    /// Stores positions of games played by a rule variant instead of the
+    /// standard rules.
+    #[must_use]
+    pub fn with_rule_set(mut self, rule_set: RuleSet) -> Self {
+        self.rule_set = rule_set;
+        self
+    }
+
+    /// Stores positions of games with the collapse chosen by another player
+    /// than the opponent of the cycle creator.
+    #[must_use]
+    pub fn with_collapse_chooser(mut self, collapse_chooser: CollapseChooser) -> Self {
+        self.collapse_chooser = collapse_chooser;
+        self
+    }
+
+    /// Solves every late position reachable from the game, those with at most
+    /// `uncollapsed_fields` fields left uncollapsed, with a [`Solver`] and
+    /// stores them. Earlier positions are only walked through, so generate
+    /// from games close to their end.
+    ///
+    /// # Errors
+    ///
+    /// Will return Err if the game is played on another board or by other
+    /// rules than the tablebase.
+    pub fn generate(
+        &mut self,
+        game: &Game,
+        uncollapsed_fields: usize,
+    ) -> Result<(), TablebaseError> {
+        self.check_board(game)?;
+        let mut solver = Solver::new();
+        let mut visited = HashSet::new();
+        self.store_positions(
+            &mut game.clone(),
+            uncollapsed_fields,
+            &mut solver,
+            &mut visited,
+        );
+        Ok(())
+    }
+
+    /// Value and best move of the position, None if it isn't stored or the
+    /// game is played on another board or by other rules.
+    #[must_use]
+    pub fn lookup(&self, game: &Game) -> Option<Solution> {
+        self.check_board(game).ok()?;
+        let (key, symmetry) = game.canonical_hash();
+        let solution = self.entries.get(&key)?;
+        Some(Solution::new(
+            solution.value(),
+            symmetry
+                .inverse()
+                .apply_move(solution.best_move(), self.size),
+        ))
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// # Errors
+    ///
+    /// Will return Err if the tablebase can't be written.
+    pub fn write_to<Writer: Write>(&self, writer: Writer) -> Result<(), TablebaseError> {
+        bincode::serialize_into(writer, self)
+            .change_context(TablebaseError {})
+            .attach_printable("Can't write tablebase")
+    }
+
+    /// # Errors
+    ///
+    /// Will return Err if the data isn't a tablebase.
+    pub fn read_from<Reader: Read>(reader: Reader) -> Result<Self, TablebaseError> {
+        bincode::deserialize_from(reader)
+            .change_context(TablebaseError {})
+            .attach_printable("Can't read tablebase")
+    }
+
+    /// # Errors
+    ///
+    /// Will return Err if the file can't be created or written.
+    pub fn save(&self, path: &Path) -> Result<(), TablebaseError> {
+        let file = File::create(path)
+            .change_context(TablebaseError {})
+            .attach_printable_lazy(|| format!("Can't create {}", path.display()))?;
+        self.write_to(BufWriter::new(file))
+    }
+
+    /// # Errors
+    ///
+    /// Will return Err if the file can't be opened or isn't a tablebase.
+    pub fn load(path: &Path) -> Result<Self, TablebaseError> {
+        let file = File::open(path)
+            .change_context(TablebaseError {})
+            .attach_printable_lazy(|| format!("Can't open {}", path.display()))?;
+        Self::read_from(BufReader::new(file))
+    }
+
+    fn check_board(&self, game: &Game) -> Result<(), TablebaseError> {
+        let board = game.get_board();
+        if board.get_size() != self.size || board.get_win_length() != self.win_length {
+            return Err(Report::new(TablebaseError {}).attach_printable(format!(
+                "Tablebase is for size {} and win length {}",
+                self.size, self.win_length
+            )));
+        }
+        if game.get_rule_set() != Some(self.rule_set)
+            || game.get_collapse_chooser() != self.collapse_chooser
+        {
+            return Err(Report::new(TablebaseError {}).attach_printable(format!(
+                "Tablebase is for {:?} rules with {:?} collapse chooser",
+                self.rule_set, self.collapse_chooser
+            )));
+        }
+        Ok(())
+    }
+
+    /// Stores the position with the best move turned to the stored copy if
+    /// it is late enough, then the positions after each of its moves.
+    fn store_positions(
+        &mut self,
+        game: &mut Game,
+        uncollapsed_fields: usize,
+        solver: &mut Solver,
+        visited: &mut HashSet<u64>,
+    ) {
+        let (key, symmetry) = game.canonical_hash();
+        // Symmetric copies of a walked position lead to copies of its
+        // positions
+        if !visited.insert(key) {
+            return;
+        }
+        if count_uncollapsed(game) <= uncollapsed_fields && !self.entries.contains_key(&key) {
+            // Ended games and games without moves have no solution
+            if let Some(solution) = solver.solve(game) {
+                self.entries.insert(
+                    key,
+                    Solution::new(
+                        solution.value(),
+                        symmetry.apply_move(solution.best_move(), self.size),
+                    ),
+                );
+            }
+        }
+        for player_move in game.legal_moves().collect::<Vec<_>>() {
+            let player_symbol = game.get_status().get_player_turn();
+            game.player_move(player_move, player_symbol)
+                .expect("Legal move should be valid");
+            self.store_positions(game, uncollapsed_fields, solver, visited);
+            game.undo().expect("Move should be undone");
+        }
+    }
+}
+
+/// Number of fields of the board not collapsed yet.
+#[must_use]
+pub fn count_uncollapsed(game: &Game) -> usize {
+    let board = game.get_board();
+    let size = board.get_size();
+    (0..size * size)
+        .filter(|&index| {
+            board
+                .get_collapsed(FieldCoordinate::from_usize(index, size))
+                .is_none()
+        })
+        .count()
+}
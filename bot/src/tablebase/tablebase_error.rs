@@ -0,0 +1,14 @@
+use core::fmt;
+use std::error::Error;
+use std::fmt::Formatter;
+
+#[derive(Debug)]
+pub struct TablebaseError {}
+
+impl fmt::Display for TablebaseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("Tablebase error")
+    }
+}
+
+impl Error for TablebaseError {}
@@ -0,0 +1,158 @@
+use super::*;
+use crate::evaluation::score_value;
+use crate::solver::{Outcome, Solver};
+use crate::test_util::{late_moves, replay};
+use engine::field_coordinate::FieldCoordinate;
+use engine::game::game_result::GameResult;
+use engine::player_move::Move;
+use engine::player_symbol::PlayerSymbol;
+use engine::rules::{CollapseChooser, RuleSet};
+use engine::symmetry::Symmetry;
+
+#[test]
+fn lookup_agrees_with_solver() {
+    let mut tablebase = Tablebase::new(3, 3);
+    let mut solver = Solver::new();
+    for moves in late_games() {
+        let mut game = replay(&moves, Symmetry::Identity);
+        tablebase.generate(&game, 4).unwrap();
+        let solution = tablebase.lookup(&game).unwrap();
+        assert_eq!(solution.value(), solver.solve(&game).unwrap().value());
+
+        let player_symbol = game.get_status().get_player_turn();
+        let value = match game
+            .player_move(solution.best_move(), player_symbol)
+            .unwrap()
+        {
+            GameResult::GameEnd(score, _) => score_value(&score, None),
+            _ => tablebase.lookup(&game).unwrap().value(),
+        };
+        assert_eq!(value, solution.value());
+    }
+    assert!(!tablebase.is_empty());
+}
+
+#[test]
+fn lookup_of_symmetric_positions() {
+    let mut tablebase = Tablebase::new(3, 3);
+    for moves in late_games() {
+        tablebase
+            .generate(&replay(&moves, Symmetry::Identity), 4)
+            .unwrap();
+        let value = tablebase
+            .lookup(&replay(&moves, Symmetry::Identity))
+            .unwrap()
+            .value();
+        for symmetry in Symmetry::ALL {
+            let mut game = replay(&moves, symmetry);
+            let solution = tablebase.lookup(&game).unwrap();
+            assert_eq!(solution.value(), value);
+            let player_symbol = game.get_status().get_player_turn();
+            let result = game
+                .player_move(solution.best_move(), player_symbol)
+                .unwrap();
+            if let GameResult::GameEnd(score, _) = result {
+                assert_eq!(score_value(&score, None), value);
+            } else {
+                assert_eq!(tablebase.lookup(&game).unwrap().value(), value);
+            }
+        }
+    }
+}
+
+#[test]
+fn write_and_read() {
+    let mut tablebase = Tablebase::new(3, 3);
+    let moves = late_games().next().unwrap();
+    let game = replay(&moves, Symmetry::Identity);
+    tablebase.generate(&game, 4).unwrap();
+    let mut data = Vec::new();
+    tablebase.write_to(&mut data).unwrap();
+    let read = Tablebase::read_from(data.as_slice()).unwrap();
+    assert_eq!(read.len(), tablebase.len());
+    assert_eq!(read.lookup(&game), tablebase.lookup(&game));
+    assert!(Tablebase::read_from(&data[..data.len() / 2]).is_err());
+}
+
+#[test]
+fn other_board() {
+    let mut tablebase = Tablebase::new(4, 3);
+    let game = replay(&late_games().next().unwrap(), Symmetry::Identity);
+    assert!(tablebase.generate(&game, 4).is_err());
+    assert_eq!(tablebase.lookup(&game), None);
+}
+
+#[test]
+fn other_rules() {
+    let game = replay(&late_games().next().unwrap(), Symmetry::Identity);
+    let mut tablebase = Tablebase::new(3, 3).with_rule_set(RuleSet::Misere);
+    assert!(tablebase.generate(&game, 4).is_err());
+    tablebase
+        .generate(&game.clone().with_rule_set(RuleSet::Misere), 4)
+        .unwrap();
+    assert_eq!(tablebase.lookup(&game), None);
+    assert!(tablebase
+        .lookup(&game.clone().with_rule_set(RuleSet::Misere))
+        .is_some());
+    let game = game.with_collapse_chooser(CollapseChooser::Creator);
+    assert_eq!(tablebase.lookup(&game.with_rule_set(RuleSet::Misere)), None);
+}
+
+#[test]
+fn generate_late_positions() {
+    let mut tablebase = Tablebase::new(3, 3);
+    let game = replay(&late_games().next().unwrap(), Symmetry::Identity);
+    tablebase.generate(&game, 3).unwrap();
+    let mut positions = 0;
+    for_each_position(&mut game.clone(), &mut |game| {
+        let is_late = count_uncollapsed(game) <= 3;
+        if is_late && !game.get_status().is_game_end() {
+            assert!(tablebase.lookup(game).is_some());
+            positions += 1;
+        } else {
+            assert_eq!(tablebase.lookup(game), None);
+        }
+    });
+    // Symmetric copies share an entry
+    assert!(!tablebase.is_empty());
+    assert!(tablebase.len() <= positions);
+}
+
+#[test]
+fn generate_after_game_end() {
+    let mut tablebase = Tablebase::new(3, 3);
+    let mut game = Game::new(3);
+    let _ = game.end_game(None);
+    tablebase.generate(&game, 9).unwrap();
+    assert!(tablebase.is_empty());
+    assert_eq!(tablebase.lookup(&game), None);
+}
+
+#[test]
+fn outcome_for_players() {
+    let solution = Solution::new(
+        -1,
+        Move::Classical {
+            field: FieldCoordinate { x: 0, y: 0 },
+        },
+    );
+    assert_eq!(solution.outcome(PlayerSymbol::X), Outcome::Loss);
+    assert_eq!(solution.outcome(PlayerSymbol::O), Outcome::Win);
+}
+
+/// Moves of 3x3 games played until at most four fields are left
+/// uncollapsed.
+fn late_games() -> impl Iterator<Item = Vec<(Move, PlayerSymbol)>> {
+    (0..20).filter_map(late_moves).take(3)
+}
+
+/// Calls `visit` with the game and every game reachable from it.
+fn for_each_position(game: &mut Game, visit: &mut impl FnMut(&Game)) {
+    visit(game);
+    for player_move in game.legal_moves().collect::<Vec<_>>() {
+        let player_symbol = game.get_status().get_player_turn();
+        game.player_move(player_move, player_symbol).unwrap();
+        for_each_position(game, visit);
+        game.undo().unwrap();
+    }
+}
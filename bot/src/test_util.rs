@@ -0,0 +1,74 @@
+use crate::tablebase::count_uncollapsed;
+use engine::field_coordinate::FieldCoordinate;
+use engine::game::Game;
+use engine::player_move::Move;
+use engine::player_symbol::PlayerSymbol;
+use engine::symmetry::Symmetry;
+
+pub(crate) fn mark((x1, y1): (usize, usize), (x2, y2): (usize, usize)) -> Move {
+    Move::Mark {
+        field1: FieldCoordinate { x: x1, y: y1 },
+        field2: FieldCoordinate { x: x2, y: y2 },
+    }
+}
+
+pub(crate) fn collapse((x, y): (usize, usize), index: usize) -> Move {
+    Move::Collapse {
+        field: FieldCoordinate { x, y },
+        index,
+    }
+}
+
+/// X holds two fields of the top row and has to collapse a cycle over the
+/// third one.
+pub(crate) fn x_to_collapse_for_row() -> Game {
+    let mut game = Game::new(3);
+    for (turn, column) in [(0, 0), (2, 1)] {
+        game.player_move(mark((column, 0), (column, 1)), PlayerSymbol::X)
+            .unwrap();
+        game.player_move(mark((column, 0), (column, 1)), PlayerSymbol::O)
+            .unwrap();
+        game.player_move(collapse((column, 0), turn), PlayerSymbol::X)
+            .unwrap();
+    }
+    game.player_move(mark((2, 0), (2, 2)), PlayerSymbol::X)
+        .unwrap();
+    game.player_move(mark((2, 0), (2, 2)), PlayerSymbol::O)
+        .unwrap();
+    game
+}
+
+/// Moves of a 3x3 game played with the seed until at most four fields are
+/// left uncollapsed, None if the game ends before.
+pub(crate) fn late_moves(seed: usize) -> Option<Vec<(Move, PlayerSymbol)>> {
+    let mut game = Game::new(3);
+    let mut moves = Vec::new();
+    loop {
+        if game.get_status().is_game_end() {
+            return None;
+        }
+        if count_uncollapsed(&game) <= 4 {
+            return Some(moves);
+        }
+        let legal_moves = game.legal_moves().collect::<Vec<_>>();
+        let player_move = legal_moves[(seed * 11 + moves.len() * 3) % legal_moves.len()];
+        let player_symbol = game.get_status().get_player_turn();
+        game.player_move(player_move, player_symbol).unwrap();
+        moves.push((player_move, player_symbol));
+    }
+}
+
+/// Position of the game played with the seed by [`late_moves`].
+pub(crate) fn late_position(seed: usize) -> Option<Game> {
+    late_moves(seed).map(|moves| replay(&moves, Symmetry::Identity))
+}
+
+/// 3x3 game after the moves turned by the symmetry.
+pub(crate) fn replay(moves: &[(Move, PlayerSymbol)], symmetry: Symmetry) -> Game {
+    let mut game = Game::new(3);
+    for &(player_move, player_symbol) in moves {
+        game.player_move(symmetry.apply_move(player_move, 3), player_symbol)
+            .unwrap();
+    }
+    game
+}
@@ -0,0 +1,163 @@
+use super::*;
+use ::test::Bencher;
+
+/// Marks a chain through every field of the board and closes it into a
+/// cycle with the last mark.
+fn mark_chain(size: usize) -> Option<Cycle> {
+    let mut board = Board::new(size);
+    let fields_count = size * size;
+    let coordinate = |index| FieldCoordinate::from_usize(index, size);
+    let player_symbol = |turn| {
+        if turn % 2 == 0 {
+            PlayerSymbol::X
+        } else {
+            PlayerSymbol::O
+        }
+    };
+    for turn in 0..fields_count - 2 {
+        let _ = board
+            .mark(
+                &[coordinate(turn), coordinate(turn + 1)],
+                player_symbol(turn),
+                turn,
+            )
+            .expect("Mark should be valid");
+    }
+    let turn = fields_count - 2;
+    board
+        .mark(
+            &[coordinate(fields_count - 2), coordinate(0)],
+            player_symbol(turn),
+            turn,
+        )
+        .expect("Mark should be valid")
+}
+
+#[bench]
+fn mark_chain_3x3(bencher: &mut Bencher) {
+    bencher.iter(|| mark_chain(3));
+}
+
+#[bench]
+fn mark_chain_5x5(bencher: &mut Bencher) {
+    bencher.iter(|| mark_chain(5));
+}
+
+#[bench]
+fn mark_chain_7x7(bencher: &mut Bencher) {
+    bencher.iter(|| mark_chain(7));
+}
+
+#[bench]
+fn mark_chain_9x9(bencher: &mut Bencher) {
+    bencher.iter(|| mark_chain(9));
+}
+
+#[bench]
+fn clone_board_9x9(bencher: &mut Bencher) {
+    let mut board = Board::new(9);
+    for turn in 0..40 {
+        let _ = board
+            .mark(
+                &[
+                    FieldCoordinate::from_usize(2 * turn, 9),
+                    FieldCoordinate::from_usize(2 * turn + 1, 9),
+                ],
+                PlayerSymbol::X,
+                turn,
+            )
+            .expect("Mark should be valid");
+    }
+    bencher.iter(|| board.clone());
+}
+
+#[bench]
+fn mark_and_unmake_9x9(bencher: &mut Bencher) {
+    let mut board = Board::new(9);
+    for turn in 0..40 {
+        let _ = board
+            .mark(
+                &[
+                    FieldCoordinate::from_usize(2 * turn, 9),
+                    FieldCoordinate::from_usize(2 * turn + 1, 9),
+                ],
+                PlayerSymbol::X,
+                turn,
+            )
+            .expect("Mark should be valid");
+    }
+    bencher.iter(|| {
+        let _ = board
+            .mark(
+                &[
+                    FieldCoordinate::from_usize(0, 9),
+                    FieldCoordinate::from_usize(80, 9),
+                ],
+                PlayerSymbol::O,
+                40,
+            )
+            .expect("Mark should be valid");
+        board.unmake()
+    });
+}
+
+/// Fields of the marks of a chain through every field of the board, the
+/// last mark closing it into a cycle.
+fn chain_marks(size: usize) -> Vec<(usize, usize)> {
+    let fields_count = size * size;
+    (0..fields_count - 2)
+        .map(|field| (field, field + 1))
+        .chain(iter::once((fields_count - 2, 0)))
+        .collect()
+}
+
+/// Finds the mark closing the chain with an A* search between the fields of
+/// every mark, as the board did before it kept components.
+fn close_chain_astar(size: usize) -> Option<usize> {
+    let mut connections: Graph<(), usize, Undirected> =
+        Graph::from_elements(iter::repeat_n(Element::Node { weight: () }, size * size));
+    for (turn, (first, second)) in chain_marks(size).into_iter().enumerate() {
+        let (first, second) = (NodeIndex::new(first), NodeIndex::new(second));
+        if astar(&connections, first, |finish| finish == second, |_| 1, |_| 0).is_some() {
+            return Some(turn);
+        }
+        connections.add_edge(first, second, turn);
+    }
+    None
+}
+
+/// Finds the mark closing the chain with the components of the board.
+fn close_chain_components(size: usize) -> Option<usize> {
+    let mut components = Components::new(size * size);
+    chain_marks(size)
+        .into_iter()
+        .position(|(first, second)| components.union(first, second).is_none())
+}
+
+#[test]
+fn close_chain_agrees() {
+    for size in [3, 5, 9] {
+        assert_eq!(close_chain_astar(size), Some(size * size - 2));
+        assert_eq!(close_chain_components(size), close_chain_astar(size));
+    }
+}
+
+#[bench]
+fn close_chain_astar_3x3(bencher: &mut Bencher) {
+    bencher.iter(|| close_chain_astar(3));
+}
+
+#[bench]
+fn close_chain_components_3x3(bencher: &mut Bencher) {
+    bencher.iter(|| close_chain_components(3));
+}
+
+#[bench]
+fn close_chain_astar_9x9(bencher: &mut Bencher) {
+    bencher.iter(|| close_chain_astar(9));
+}
+
+#[bench]
+fn close_chain_components_9x9(bencher: &mut Bencher) {
+    bencher.iter(|| close_chain_components(9));
+}
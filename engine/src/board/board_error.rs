@@ -1,15 +1,59 @@
 use crate::field_coordinate::FieldCoordinate;
 use core::fmt;
+use serde::{Deserialize, Serialize};
 use std::error::Error;
 use std::fmt::Formatter;
 
+/// Reason why the board rejected a move.
+#[derive(Debug, PartialEq, Copy, Clone, Serialize, Deserialize)]
+pub enum BoardErrorKind {
+    /// Both marks of a move are on the same field.
+    SameCoordinates,
+    /// The field is outside of the board.
+    OutOfBoard,
+    /// The field has already collapsed.
+    FieldCollapsed,
+    /// A collapse was requested without a pending cycle.
+    NoCycle,
+    /// The collapsed field isn't part of the pending cycle.
+    FieldNotInCycle,
+    /// The field of the cycle has no mark with the chosen index.
+    IndexNotInField,
+    /// The board reached a state that should be impossible.
+    Inconsistent,
+}
+
+impl fmt::Display for BoardErrorKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            BoardErrorKind::SameCoordinates => "same coordinates",
+            BoardErrorKind::OutOfBoard => "out of board coordinate",
+            BoardErrorKind::FieldCollapsed => "field is collapsed",
+            BoardErrorKind::NoCycle => "no cycle found",
+            BoardErrorKind::FieldNotInCycle => "no coordinate found in cycle",
+            BoardErrorKind::IndexNotInField => "no index found in coordinate",
+            BoardErrorKind::Inconsistent => "inconsistent board",
+        })
+    }
+}
+
 #[derive(Debug)]
 pub struct BoardError {
     field_coordinate: FieldCoordinate,
+    kind: BoardErrorKind,
 }
 impl BoardError {
-    pub fn new(field_coordinate: FieldCoordinate) -> Self {
-        BoardError { field_coordinate }
+    #[must_use]
+    pub fn new(field_coordinate: FieldCoordinate, kind: BoardErrorKind) -> Self {
+        BoardError {
+            field_coordinate,
+            kind,
+        }
+    }
+
+    #[must_use]
+    pub fn kind(&self) -> BoardErrorKind {
+        self.kind
     }
 }
 
@@ -17,8 +61,8 @@ impl fmt::Display for BoardError {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         write!(
             f,
-            "Board error (wrong coordinates x:{} y:{})",
-            self.field_coordinate.x, self.field_coordinate.y
+            "Board error ({} x:{} y:{})",
+            self.kind, self.field_coordinate.x, self.field_coordinate.y
         )
     }
 }
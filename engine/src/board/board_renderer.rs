@@ -0,0 +1,157 @@
+use crate::board::Board;
+use crate::cycle::Cycle;
+use crate::field::Field;
+use crate::field_coordinate::column_name;
+use crate::player_symbol::PlayerSymbol;
+
+const CELL_HEIGHT: usize = 3;
+
+/// Draws boards and cycles as text. Entangled fields list their spooky
+/// marks like `X1 O2`, collapsed fields are drawn as a big symbol with the
+/// subscript of the mark they collapsed to.
+#[derive(Debug, Clone, Copy)]
+pub struct BoardRenderer {
+    first_subscript: usize,
+    labels: bool,
+}
+
+impl Default for BoardRenderer {
+    fn default() -> Self {
+        BoardRenderer {
+            first_subscript: 1,
+            labels: true,
+        }
+    }
+}
+
+impl BoardRenderer {
+    /// Subscript written for the mark of the first turn, 1 by default.
+    #[must_use]
+    pub fn with_first_subscript(mut self, first_subscript: usize) -> Self {
+        self.first_subscript = first_subscript;
+        self
+    }
+
+    /// Whether columns are labelled with letters and rows with numbers,
+    /// as fields are named in moves. Enabled by default.
+    #[must_use]
+    pub fn with_labels(mut self, labels: bool) -> Self {
+        self.labels = labels;
+        self
+    }
+
+    #[must_use]
+    pub fn render(&self, board: &Board) -> String {
+        let positions = board.get_positions();
+        let size = board.get_size();
+        let cells = positions
+            .rows_iter()
+            .map(|row| row.map(|field| self.cell(field)).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        let width = cells
+            .iter()
+            .flatten()
+            .flatten()
+            .map(String::len)
+            .max()
+            .unwrap_or(0);
+        let label_width = if self.labels {
+            size.to_string().len()
+        } else {
+            0
+        };
+        let margin = if self.labels {
+            " ".repeat(label_width + 1)
+        } else {
+            String::new()
+        };
+
+        let mut lines = Vec::new();
+        if self.labels {
+            let header = (0..size)
+                .map(|x| format!(" {:^width$} ", column_name(x)))
+                .collect::<Vec<_>>()
+                .join(" ");
+            lines.push(format!("{margin}{header}"));
+        }
+        let separator = vec!["-".repeat(width + 2); size].join("+");
+        for (y, row) in cells.iter().enumerate() {
+            if y > 0 {
+                lines.push(format!("{margin}{separator}"));
+            }
+            for line in 0..CELL_HEIGHT {
+                let label = if self.labels && line == CELL_HEIGHT / 2 {
+                    format!("{:>label_width$} ", y + 1)
+                } else {
+                    margin.clone()
+                };
+                let cells_line = row
+                    .iter()
+                    .map(|cell| format!(" {:<width$} ", cell[line]))
+                    .collect::<Vec<_>>()
+                    .join("|");
+                lines.push(format!("{label}{cells_line}"));
+            }
+        }
+        lines
+            .iter()
+            .map(|line| line.trim_end())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Lists the fields of the cycle with the subscripts of the marks the
+    /// field can collapse to, e.g. `a1 (1, 2) - b1 (2, 3)`.
+    #[must_use]
+    pub fn render_cycle(&self, cycle: &Cycle) -> String {
+        cycle
+            .get_fields_coordinate()
+            .iter()
+            .zip(cycle.get_fields_indexes())
+            .map(|(field_coordinate, indexes)| {
+                let subscripts = indexes
+                    .iter()
+                    .map(|&index| (index + self.first_subscript).to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                format!("{field_coordinate} ({subscripts})")
+            })
+            .collect::<Vec<_>>()
+            .join(" - ")
+    }
+
+    fn cell(&self, field: &Field) -> [String; CELL_HEIGHT] {
+        match field {
+            Field::Entangled(symbols) => {
+                let marks = symbols
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(turn, player_symbol)| {
+                        player_symbol.map(|player_symbol| {
+                            format!("{player_symbol}{}", turn + self.first_subscript)
+                        })
+                    })
+                    .collect::<Vec<_>>();
+                let mut lines = marks
+                    .chunks(marks.len().div_ceil(CELL_HEIGHT).max(1))
+                    .map(|marks| marks.join(" "));
+                std::array::from_fn(|_| lines.next().unwrap_or_default())
+            }
+            Field::Collapsed(player_symbol, turn) => {
+                let subscript = turn + self.first_subscript;
+                match player_symbol {
+                    PlayerSymbol::X => [
+                        r"\ /".to_string(),
+                        " X ".to_string(),
+                        format!(r"/ \{subscript}"),
+                    ],
+                    PlayerSymbol::O => [
+                        r"/-\".to_string(),
+                        "| |".to_string(),
+                        format!(r"\-/{subscript}"),
+                    ],
+                }
+            }
+        }
+    }
+}
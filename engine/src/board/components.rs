@@ -0,0 +1,63 @@
+/// Sets of fields joined by a chain of entanglements. Union by rank without
+/// path compression keeps every union a single link, so the last union can
+/// be taken back exactly.
+#[derive(Debug, Clone)]
+pub(super) struct Components {
+    parent: Vec<usize>,
+    rank: Vec<u8>,
+}
+
+/// Link made by [`Components::union`], needed to split it again.
+#[derive(Debug, Clone, Copy)]
+pub(super) struct Link {
+    child: usize,
+    rank_increased: bool,
+}
+
+impl Components {
+    pub(super) fn new(fields: usize) -> Components {
+        Components {
+            parent: (0..fields).collect(),
+            rank: vec![0; fields],
+        }
+    }
+
+    pub(super) fn find(&self, mut field: usize) -> usize {
+        while self.parent[field] != field {
+            field = self.parent[field];
+        }
+        field
+    }
+
+    /// Joins the sets of both fields. Returns None if they were already in
+    /// the same set.
+    pub(super) fn union(&mut self, first: usize, second: usize) -> Option<Link> {
+        let (first, second) = (self.find(first), self.find(second));
+        if first == second {
+            return None;
+        }
+        let (child, root) = if self.rank[first] < self.rank[second] {
+            (first, second)
+        } else {
+            (second, first)
+        };
+        self.parent[child] = root;
+        let rank_increased = self.rank[child] == self.rank[root];
+        if rank_increased {
+            self.rank[root] += 1;
+        }
+        Some(Link {
+            child,
+            rank_increased,
+        })
+    }
+
+    /// Takes back the last union.
+    pub(super) fn split(&mut self, link: Link) {
+        let root = self.parent[link.child];
+        self.parent[link.child] = link.child;
+        if link.rank_increased {
+            self.rank[root] -= 1;
+        }
+    }
+}
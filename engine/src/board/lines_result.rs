@@ -1,8 +1,8 @@
-use crate::player_symbol::PlayerSymbol;
+use crate::line::Line;
 
+/// Lines completed on the board.
 pub struct LinesResult {
-    x: usize,
-    o: usize,
+    lines: Vec<Line>,
 }
 
 impl Default for LinesResult {
@@ -13,27 +13,14 @@ impl Default for LinesResult {
 
 impl LinesResult {
     pub fn new() -> LinesResult {
-        LinesResult { x: 0, o: 0 }
+        LinesResult { lines: Vec::new() }
     }
 
-    pub fn increase(&mut self, player_symbol: PlayerSymbol) {
-        match player_symbol {
-            PlayerSymbol::X => self.x += 1,
-            PlayerSymbol::O => self.o += 1,
-        }
+    pub fn add_line(&mut self, line: Line) {
+        self.lines.push(line);
     }
 
-    pub fn is_full_line(&self) -> bool {
-        self.x != 0 || self.o != 0
-    }
-
-    pub fn get_winner(&self) -> Option<PlayerSymbol> {
-        if self.x > self.o {
-            return Some(PlayerSymbol::X);
-        }
-        if self.o > self.x {
-            return Some(PlayerSymbol::O);
-        }
-        None
+    pub fn get_lines(&self) -> &[Line] {
+        &self.lines
     }
 }
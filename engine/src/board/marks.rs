@@ -0,0 +1,66 @@
+use crate::player_symbol::PlayerSymbol;
+use fixedbitset::FixedBitSet;
+
+/// Spooky marks of every field of a board, kept as bitsets of turns per
+/// player. The turns of one field take a block of `turns` bits, so a whole
+/// board is held in two allocations.
+#[derive(Debug, Clone)]
+pub(super) struct Marks {
+    turns: usize,
+    x: FixedBitSet,
+    o: FixedBitSet,
+}
+
+impl Marks {
+    pub(super) fn new(fields: usize, turns: usize) -> Marks {
+        Marks {
+            turns,
+            x: FixedBitSet::with_capacity(fields * turns),
+            o: FixedBitSet::with_capacity(fields * turns),
+        }
+    }
+
+    pub(super) fn insert(&mut self, field: usize, player_symbol: PlayerSymbol, turn: usize) {
+        let bit = self.bit(field, turn);
+        match player_symbol {
+            PlayerSymbol::X => self.x.insert(bit),
+            PlayerSymbol::O => self.o.insert(bit),
+        }
+    }
+
+    pub(super) fn remove(&mut self, field: usize, turn: usize) {
+        let bit = self.bit(field, turn);
+        self.x.set(bit, false);
+        self.o.set(bit, false);
+    }
+
+    /// Returns the symbol marked on the field in the given turn.
+    pub(super) fn get(&self, field: usize, turn: usize) -> Option<PlayerSymbol> {
+        let bit = self.bit(field, turn);
+        if self.x.contains(bit) {
+            Some(PlayerSymbol::X)
+        } else if self.o.contains(bit) {
+            Some(PlayerSymbol::O)
+        } else {
+            None
+        }
+    }
+
+    /// Whether the player has a mark on the field in any turn.
+    pub(super) fn contains(&self, field: usize, player_symbol: PlayerSymbol) -> bool {
+        let bits = self.bit(field, 0)..self.bit(field + 1, 0);
+        match player_symbol {
+            PlayerSymbol::X => self.x.count_ones(bits) > 0,
+            PlayerSymbol::O => self.o.count_ones(bits) > 0,
+        }
+    }
+
+    /// Returns the marks of the field indexed by turn.
+    pub(super) fn get_field(&self, field: usize) -> Vec<Option<PlayerSymbol>> {
+        (0..self.turns).map(|turn| self.get(field, turn)).collect()
+    }
+
+    fn bit(&self, field: usize, turn: usize) -> usize {
+        field * self.turns + turn
+    }
+}
@@ -1,33 +1,82 @@
-mod board_error;
+#[cfg(test)]
+mod bench;
+#[allow(clippy::module_name_repetitions)]
+pub mod board_error;
+pub mod board_renderer;
+mod components;
 mod lines_result;
+mod marks;
 #[cfg(test)]
 mod test;
 
 use std::collections::HashSet;
-use std::iter;
-use std::iter::{zip, Peekable};
+use std::fmt::Formatter;
+use std::{fmt, iter};
 
-use crate::board::board_error::BoardError;
+use crate::board::board_error::{BoardError, BoardErrorKind};
+use crate::board::board_renderer::BoardRenderer;
+use crate::board::components::{Components, Link};
 use crate::board::lines_result::LinesResult;
+use crate::board::marks::Marks;
 use crate::cycle::Cycle;
 use crate::field::Field;
 use crate::field_coordinate::FieldCoordinate;
+use crate::line::{Line, LineDirection};
 use crate::player_symbol::PlayerSymbol;
+use crate::symmetry::Symmetry;
+use crate::zobrist;
 use crate::DEFAULT_BOARD_SIZE;
 use array2d::Array2D;
 use error_stack::{Report, Result};
 use petgraph::algo::astar;
 use petgraph::data::{Element, FromElements};
 use petgraph::stable_graph::NodeIndex;
-use petgraph::visit::NodeIndexable;
+use petgraph::visit::{EdgeRef, NodeIndexable};
 use petgraph::{Graph, Undirected};
 
 #[derive(Debug, Clone)]
 pub struct Board {
     size: usize,
-    positions: Array2D<Field>,
+    win_length: usize,
+    /// Symbol and turn subscript of every collapsed field.
+    collapsed: Array2D<Option<(PlayerSymbol, usize)>>,
+    marks: Marks,
     connections: Graph<(), usize, Undirected>,
+    /// Fields joined by a chain of entanglements share a set. Collapsing
+    /// removes whole components, so sets never have to be split.
+    components: Components,
     last_cycle: Option<Cycle>,
+    /// Changes of the moves made on the board, newest last.
+    changes: Vec<BoardChange>,
+    /// Zobrist hash of the marks of uncollapsed fields, the collapsed fields
+    /// and the pending cycle, kept up to date by every change.
+    hash: u64,
+}
+
+/// What a move changed on the board, enough to take it back.
+#[derive(Debug, Clone)]
+enum BoardChange {
+    Mark {
+        fields: [usize; 2],
+        turn: usize,
+        /// Set when the mark joined two components instead of closing a cycle.
+        link: Option<Link>,
+    },
+    Collapse {
+        collapsed: Vec<FieldCoordinate>,
+        /// Entanglements removed by the collapse as nodes and turn.
+        edges: Vec<(usize, usize, usize)>,
+        last_cycle: Cycle,
+    },
+    Classical {
+        field_coordinate: FieldCoordinate,
+    },
+}
+
+impl fmt::Display for Board {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", BoardRenderer::default().render(self))
+    }
 }
 
 impl Default for Board {
@@ -38,134 +87,346 @@ impl Default for Board {
 
 impl Board {
     pub(super) fn new(size: usize) -> Board {
+        Board::with_win_length(size, size)
+    }
+
+    /// Board on which `win_length` collapsed fields in a row, column or
+    /// diagonal complete a line.
+    pub(super) fn with_win_length(size: usize, win_length: usize) -> Board {
         Board {
             size,
-            positions: Array2D::filled_with(Field::Entangled(vec![None; size * size]), size, size),
+            win_length,
+            collapsed: Array2D::filled_with(None, size, size),
+            marks: Marks::new(size * size, size * size),
             connections: Graph::from_elements(iter::repeat_n(
                 Element::Node { weight: () },
                 size * size,
             )),
+            components: Components::new(size * size),
             last_cycle: None,
+            changes: Vec::new(),
+            hash: 0,
         }
     }
 
+    /// Rebuilds a board from its positions, the entanglements between fields
+    /// and the pending cycle. Returns None if the parts don't describe a board
+    /// of the given size.
+    pub(super) fn from_parts(
+        size: usize,
+        win_length: usize,
+        positions: &Array2D<Field>,
+        connections: &[(FieldCoordinate, FieldCoordinate, usize)],
+        last_cycle: Option<Cycle>,
+    ) -> Option<Board> {
+        let is_valid_coordinate = |field_coordinate: &FieldCoordinate| {
+            field_coordinate.x < size && field_coordinate.y < size
+        };
+        if !(1..=size).contains(&win_length)
+            || positions.num_rows() != size
+            || positions.num_columns() != size
+            || positions.elements_row_major_iter().any(
+                |field| matches!(field, Field::Entangled(symbols) if symbols.len() != size * size),
+            )
+        {
+            return None;
+        }
+        let is_marked = |field_coordinate: &FieldCoordinate, turn: usize| {
+            is_valid_coordinate(field_coordinate)
+                && matches!(
+                    &positions[(field_coordinate.y, field_coordinate.x)],
+                    Field::Entangled(symbols) if symbols.get(turn).is_some_and(Option::is_some)
+                )
+        };
+        if !connections
+            .iter()
+            .all(|(first, second, turn)| is_marked(first, *turn) && is_marked(second, *turn))
+        {
+            return None;
+        }
+        if let Some(cycle) = &last_cycle {
+            // Every field of the cycle holds the marks joining it to its
+            // neighbours in the cycle
+            let fields_coordinates = cycle.get_fields_coordinate();
+            let turns = cycle.edge_turns()?;
+            let is_closed = turns.iter().enumerate().all(|(i, &turn)| {
+                is_marked(&fields_coordinates[i], turn)
+                    && is_marked(&fields_coordinates[(i + 1) % cycle.len()], turn)
+            });
+            let mut distinct = fields_coordinates.to_vec();
+            distinct
+                .sort_unstable_by_key(|field_coordinate| (field_coordinate.y, field_coordinate.x));
+            distinct.dedup();
+            if !is_closed || distinct.len() != cycle.len() {
+                return None;
+            }
+        }
+
+        let mut board = Board::with_win_length(size, win_length);
+        board.set_positions(positions);
+        for (first, second, turn) in connections {
+            let (first, second) = (board.get_node(first), board.get_node(second));
+            board.connections.add_edge(first, second, *turn);
+            let _ = board.components.union(first.index(), second.index());
+        }
+        board.set_last_cycle(last_cycle);
+        Some(board)
+    }
+
     #[must_use]
     pub fn get_positions(&self) -> Array2D<Field> {
-        self.positions.clone()
+        let mut index = 0;
+        Array2D::filled_by_row_major(
+            || {
+                let field = match self.collapsed[(index / self.size, index % self.size)] {
+                    Some((player_symbol, turn)) => Field::Collapsed(player_symbol, turn),
+                    None => Field::Entangled(self.marks.get_field(index)),
+                };
+                index += 1;
+                field
+            },
+            self.size,
+            self.size,
+        )
     }
 
-    pub(super) fn mark(
-        &mut self,
-        fields_coordinates: &[FieldCoordinate],
-        player_symbol: PlayerSymbol,
-        turn: usize,
-    ) -> Result<Option<Cycle>, BoardError> {
-        let mut hash_set = HashSet::<FieldCoordinate>::new();
-        // Check for errors
-        let mut fields = fields_coordinates
-            .iter()
-            .map(|&field_coordinate| {
-                let field = self.positions.get(field_coordinate.y, field_coordinate.x);
-                if hash_set.contains(&field_coordinate) {
-                    return Err(Report::new(BoardError::new(field_coordinate))
-                        .attach_printable("Same coordinates"));
-                }
-                hash_set.insert(field_coordinate);
-                match field {
-                    Some(field) => {
-                        if !matches!(field, &Field::Entangled(_)) {
-                            return Err(Report::new(BoardError::new(field_coordinate))
-                                .attach_printable("Field is already entangled"));
+    fn set_positions(&mut self, positions: &Array2D<Field>) {
+        for (index, field) in positions.elements_row_major_iter().enumerate() {
+            let field_coordinate = FieldCoordinate::from_usize(index, self.size);
+            match field {
+                Field::Entangled(symbols) => {
+                    for (turn, player_symbol) in symbols.iter().enumerate() {
+                        if let Some(player_symbol) = player_symbol {
+                            self.insert_mark(index, *player_symbol, turn);
                         }
-                        Ok(field.clone())
                     }
-                    None => Err(Report::new(BoardError::new(field_coordinate))
-                        .attach_printable("Out of band coordinate")),
                 }
-            })
-            .try_collect::<Vec<Field>>()?;
-
-        // zip fields_coordinates with fields
-        zip(
-            fields.iter_mut().filter_map(|field| match field {
-                Field::Entangled(value) => {
-                    value[turn] = Some(player_symbol);
-                    Some(Field::Entangled(value.to_owned()))
+                &Field::Collapsed(player_symbol, turn) => {
+                    self.set_collapse(&field_coordinate, player_symbol, turn);
                 }
-                Field::Collapsed(_) => None,
-            }),
-            fields_coordinates,
-        )
-        .for_each(|(field, &field_coordinate)| {
-            self.positions
-                .set(field_coordinate.y, field_coordinate.x, field)
-                .expect("Coordinates should be valid");
-        });
+            }
+        }
+    }
 
-        let nodes = fields_coordinates
-            .iter()
-            .map(|&field_coordinate| {
-                self.connections
-                    .from_index(FieldCoordinate::into_usize(field_coordinate, self.size))
+    #[must_use]
+    pub fn get_size(&self) -> usize {
+        self.size
+    }
+
+    #[must_use]
+    pub fn get_win_length(&self) -> usize {
+        self.win_length
+    }
+
+    /// Symbol and turn of the mark the field collapsed to, None while it is
+    /// entangled. Reads the field without building the positions.
+    #[must_use]
+    pub fn get_collapsed(
+        &self,
+        field_coordinate: FieldCoordinate,
+    ) -> Option<(PlayerSymbol, usize)> {
+        self.collapsed[(field_coordinate.y, field_coordinate.x)]
+    }
+
+    /// Whether the uncollapsed field holds a spooky mark of the player.
+    #[must_use]
+    pub fn has_mark(&self, field_coordinate: FieldCoordinate, player_symbol: PlayerSymbol) -> bool {
+        self.get_collapsed(field_coordinate).is_none()
+            && self.marks.contains(
+                FieldCoordinate::into_usize(field_coordinate, self.size),
+                player_symbol,
+            )
+    }
+
+    /// Entanglements between fields, ordered by turn.
+    pub(super) fn get_connections(&self) -> Vec<(FieldCoordinate, FieldCoordinate, usize)> {
+        let mut connections = self
+            .connections
+            .edge_references()
+            .map(|edge| {
+                (
+                    FieldCoordinate::from_usize(edge.source().index(), self.size),
+                    FieldCoordinate::from_usize(edge.target().index(), self.size),
+                    *edge.weight(),
+                )
             })
             .collect::<Vec<_>>();
+        connections.sort_unstable_by_key(|&(_, _, turn)| turn);
+        connections
+    }
 
-        // Check for cycle
-        let path = astar(
-            &self.connections,
-            nodes[0],
-            |finish| finish == nodes[1],
-            |_| 1,
-            |_| 0,
-        );
-        if path.is_some() {
-            self.last_cycle = Some(self.map_cycle(path, turn));
-            Ok(self.last_cycle.clone())
-        } else {
+    pub(super) fn get_entangled_coordinates(&self) -> Vec<FieldCoordinate> {
+        (0..self.size * self.size)
+            .map(|index| FieldCoordinate::from_usize(index, self.size))
+            .filter(|field_coordinate| {
+                self.collapsed
+                    .get(field_coordinate.y, field_coordinate.x)
+                    .is_some_and(Option::is_none)
+            })
+            .collect()
+    }
+
+    /// Cycle waiting to be collapsed.
+    #[must_use]
+    pub fn get_last_cycle(&self) -> Option<&Cycle> {
+        self.last_cycle.as_ref()
+    }
+
+    /// Checks that the fields of a mark are distinct uncollapsed fields of the
+    /// board, without changing it.
+    pub(super) fn validate_mark(
+        &self,
+        fields_coordinates: &[FieldCoordinate],
+    ) -> Result<(), BoardError> {
+        let mut hash_set = HashSet::<FieldCoordinate>::new();
+        fields_coordinates.iter().try_for_each(|&field_coordinate| {
+            let field = self.collapsed.get(field_coordinate.y, field_coordinate.x);
+            if hash_set.contains(&field_coordinate) {
+                return Err(Report::new(BoardError::new(
+                    field_coordinate,
+                    BoardErrorKind::SameCoordinates,
+                )));
+            }
+            hash_set.insert(field_coordinate);
+            match field {
+                Some(None) => Ok(()),
+                Some(Some(_)) => Err(Report::new(BoardError::new(
+                    field_coordinate,
+                    BoardErrorKind::FieldCollapsed,
+                ))),
+                None => Err(Report::new(BoardError::new(
+                    field_coordinate,
+                    BoardErrorKind::OutOfBoard,
+                ))),
+            }
+        })
+    }
+
+    pub(super) fn mark(
+        &mut self,
+        fields_coordinates: &[FieldCoordinate],
+        player_symbol: PlayerSymbol,
+        turn: usize,
+    ) -> Result<Option<Cycle>, BoardError> {
+        self.validate_mark(fields_coordinates)?;
+
+        let fields = [
+            FieldCoordinate::into_usize(fields_coordinates[0], self.size),
+            FieldCoordinate::into_usize(fields_coordinates[1], self.size),
+        ];
+        for field in fields {
+            self.insert_mark(field, player_symbol, turn);
+        }
+        let nodes = fields.map(|field| self.connections.from_index(field));
+
+        // Check for cycle, the path is only searched once the fields are connected
+        let link = self.components.union(fields[0], fields[1]);
+        self.changes.push(BoardChange::Mark { fields, turn, link });
+        if link.is_some() {
             self.connections.add_edge(nodes[0], nodes[1], turn);
             Ok(None)
+        } else {
+            let path = astar(
+                &self.connections,
+                nodes[0],
+                |finish| finish == nodes[1],
+                |_| 1,
+                |_| 0,
+            );
+            let cycle = self.map_cycle(path, turn);
+            self.set_last_cycle(Some(cycle));
+            Ok(self.last_cycle.clone())
         }
     }
 
-    pub(super) fn collapse(
-        &mut self,
+    /// Checks that the field is in the pending cycle and can collapse to the
+    /// mark of the given turn, without changing the board.
+    pub(super) fn validate_collapse(
+        &self,
         field_coordinate: FieldCoordinate,
         index: usize,
-    ) -> Result<(), BoardError> {
-        // Check for errors
-        let mut last_cycle = match &self.last_cycle {
-            Some(cycle) => cycle.clone(),
-            None => {
-                return Err(Report::new(BoardError::new(field_coordinate))
-                    .attach_printable("No cycle found"))
-            }
+    ) -> Result<usize, BoardError> {
+        let Some(last_cycle) = &self.last_cycle else {
+            return Err(Report::new(BoardError::new(
+                field_coordinate,
+                BoardErrorKind::NoCycle,
+            )));
         };
         let Some(n) = last_cycle
             .get_fields_coordinate()
             .iter()
             .position(|&coordinate| coordinate == field_coordinate)
         else {
-            return Err(Report::new(BoardError::new(field_coordinate))
-                .attach_printable("No coordinate found in cycle"));
+            return Err(Report::new(BoardError::new(
+                field_coordinate,
+                BoardErrorKind::FieldNotInCycle,
+            )));
         };
         if !last_cycle.get_fields_indexes()[n].contains(&index) {
-            return Err(Report::new(BoardError::new(field_coordinate))
-                .attach_printable("No index found in coordinate"));
+            return Err(Report::new(BoardError::new(
+                field_coordinate,
+                BoardErrorKind::IndexNotInField,
+            )));
         }
+        Ok(n)
+    }
 
+    pub(super) fn collapse(
+        &mut self,
+        field_coordinate: FieldCoordinate,
+        index: usize,
+    ) -> Result<(), BoardError> {
+        let n = self.validate_collapse(field_coordinate, index)?;
+        let last_cycle = self
+            .last_cycle
+            .clone()
+            .expect("Cycle should exist after validation");
+        self.set_last_cycle(None);
+        let mut collapsed = Vec::new();
+        let mut edges = Vec::new();
+        match self.collapse_cycle(last_cycle.clone(), n, index, &mut collapsed, &mut edges) {
+            Ok(()) => {
+                self.changes.push(BoardChange::Collapse {
+                    collapsed,
+                    edges,
+                    last_cycle,
+                });
+                Ok(())
+            }
+            Err(err) => {
+                self.revert_collapse(&collapsed, &edges, last_cycle);
+                Err(err)
+            }
+        }
+    }
+
+    /// Collapses the cycle starting from its n-th field, recording the
+    /// collapsed fields and removed entanglements.
+    fn collapse_cycle(
+        &mut self,
+        mut last_cycle: Cycle,
+        n: usize,
+        index: usize,
+        collapsed: &mut Vec<FieldCoordinate>,
+        edges: &mut Vec<(usize, usize, usize)>,
+    ) -> Result<(), BoardError> {
         //Collapse cycle
         last_cycle.shift(n);
-        let mut last_edge_weight = index;
-        let mut last_field_coordinate = field_coordinate;
         let cycle_len = last_cycle.len();
+        if !last_cycle.get_fields_indexes()[1 % cycle_len].contains(&index) {
+            last_cycle.reverse();
+        }
+        let mut last_edge_weight = index;
+        let mut last_field_coordinate = *last_cycle.get_field_coordinate(0);
 
         for i in 0..cycle_len {
             last_edge_weight = last_cycle.remove((i + 1) % cycle_len, last_edge_weight);
             let field_coordinate = last_cycle.get_field_coordinate((i + 1) % cycle_len);
             let player_symbol =
                 self.get_player_symbol_from_entangled(field_coordinate, last_edge_weight)?;
-            self.set_collapse(field_coordinate, player_symbol);
-            self.remove_edge(field_coordinate, &last_field_coordinate);
+            self.set_collapse(field_coordinate, player_symbol, last_edge_weight);
+            collapsed.push(*field_coordinate);
+            edges.extend(self.remove_edge(field_coordinate, &last_field_coordinate));
             last_field_coordinate = *field_coordinate;
         }
 
@@ -184,21 +445,106 @@ impl Board {
                 nodes_indexes.push(neighbor);
                 let neighbor_coordinate = FieldCoordinate::from_usize(neighbor.index(), self.size);
                 if let Some(edge) = self.connections.find_edge(node, neighbor) {
-                    let player_symbol = self.get_player_symbol_from_entangled(
-                        &neighbor_coordinate,
-                        *self
-                            .connections
-                            .edge_weight(edge)
-                            .expect("Edge should exist"),
-                    )?;
-                    to_collapse.push((neighbor_coordinate, player_symbol));
+                    let turn = *self
+                        .connections
+                        .edge_weight(edge)
+                        .expect("Edge should exist");
+                    let player_symbol =
+                        self.get_player_symbol_from_entangled(&neighbor_coordinate, turn)?;
+                    to_collapse.push((neighbor_coordinate, player_symbol, turn));
+                }
+            }
+            for (neighbor_coordinate, player_symbol, turn) in to_collapse {
+                self.set_collapse(&neighbor_coordinate, player_symbol, turn);
+                collapsed.push(neighbor_coordinate);
+                edges.extend(self.remove_edge(&node_coordinate, &neighbor_coordinate));
+            }
+        }
+        Ok(())
+    }
+
+    fn revert_collapse(
+        &mut self,
+        collapsed: &[FieldCoordinate],
+        edges: &[(usize, usize, usize)],
+        last_cycle: Cycle,
+    ) {
+        for field_coordinate in collapsed {
+            self.clear_collapse(field_coordinate);
+        }
+        for &(first, second, turn) in edges {
+            self.connections.add_edge(
+                self.connections.from_index(first),
+                self.connections.from_index(second),
+                turn,
+            );
+        }
+        self.set_last_cycle(Some(last_cycle));
+    }
+
+    /// Takes back the last move made on the board, restoring the state from
+    /// before it. Returns false if no move was made.
+    pub(super) fn unmake(&mut self) -> bool {
+        let Some(change) = self.changes.pop() else {
+            return false;
+        };
+        match change {
+            BoardChange::Mark { fields, turn, link } => {
+                for field in fields {
+                    self.remove_mark(field, turn);
+                }
+                match link {
+                    Some(link) => {
+                        self.components.split(link);
+                        let nodes = fields.map(|field| self.connections.from_index(field));
+                        if let Some(edge) = self.connections.find_edge(nodes[0], nodes[1]) {
+                            self.connections.remove_edge(edge);
+                        }
+                    }
+                    None => self.set_last_cycle(None),
                 }
             }
-            for (neighbor_coordinate, player_symbol) in to_collapse {
-                self.set_collapse(&neighbor_coordinate, player_symbol);
-                self.remove_edge(&node_coordinate, &neighbor_coordinate);
+            BoardChange::Collapse {
+                collapsed,
+                edges,
+                last_cycle,
+            } => self.revert_collapse(&collapsed, &edges, last_cycle),
+            BoardChange::Classical { field_coordinate } => {
+                self.clear_collapse(&field_coordinate);
             }
         }
+        true
+    }
+
+    /// Checks that the field is an uncollapsed field of the board, without
+    /// changing it.
+    pub(super) fn validate_classical(
+        &self,
+        field_coordinate: FieldCoordinate,
+    ) -> Result<(), BoardError> {
+        match self.collapsed.get(field_coordinate.y, field_coordinate.x) {
+            Some(None) => Ok(()),
+            Some(Some(_)) => Err(Report::new(BoardError::new(
+                field_coordinate,
+                BoardErrorKind::FieldCollapsed,
+            ))),
+            None => Err(Report::new(BoardError::new(
+                field_coordinate,
+                BoardErrorKind::OutOfBoard,
+            ))),
+        }
+    }
+
+    pub(super) fn classical(
+        &mut self,
+        field_coordinate: FieldCoordinate,
+        player_symbol: PlayerSymbol,
+        turn: usize,
+    ) -> Result<(), BoardError> {
+        self.validate_classical(field_coordinate)?;
+        self.set_collapse(&field_coordinate, player_symbol, turn);
+        self.changes
+            .push(BoardChange::Classical { field_coordinate });
         Ok(())
     }
 
@@ -207,38 +553,137 @@ impl Board {
         field_coordinate: &FieldCoordinate,
         index: usize,
     ) -> Result<PlayerSymbol, BoardError> {
-        match self
-            .positions
+        if self
+            .collapsed
             .get(field_coordinate.y, field_coordinate.x)
             .expect("Coordinate should be valid")
+            .is_some()
         {
-            Field::Entangled(symbols) => Ok(symbols[index].expect("Index should be valid")),
-            Field::Collapsed(_) => Err(Report::new(BoardError::new(*field_coordinate))
-                .attach_printable("Field is collapsed")),
+            return Err(Report::new(BoardError::new(
+                *field_coordinate,
+                BoardErrorKind::Inconsistent,
+            )));
+        }
+        Ok(self
+            .marks
+            .get(
+                FieldCoordinate::into_usize(*field_coordinate, self.size),
+                index,
+            )
+            .expect("Index should be valid"))
+    }
+
+    fn insert_mark(&mut self, field: usize, player_symbol: PlayerSymbol, turn: usize) {
+        self.marks.insert(field, player_symbol, turn);
+        self.hash ^= zobrist::mark_key(field, player_symbol, turn);
+    }
+
+    fn remove_mark(&mut self, field: usize, turn: usize) {
+        if let Some(player_symbol) = self.marks.get(field, turn) {
+            self.hash ^= zobrist::mark_key(field, player_symbol, turn);
         }
+        self.marks.remove(field, turn);
+    }
+
+    /// Hash of the marks of the field, which only count while it is
+    /// uncollapsed.
+    fn field_marks_hash(&self, field: usize, hash_field: usize) -> u64 {
+        self.marks
+            .get_field(field)
+            .into_iter()
+            .enumerate()
+            .filter_map(|(turn, player_symbol)| {
+                player_symbol
+                    .map(|player_symbol| zobrist::mark_key(hash_field, player_symbol, turn))
+            })
+            .fold(0, |hash, key| hash ^ key)
     }
 
-    fn set_collapse(&mut self, field_coordinate: &FieldCoordinate, player_symbol: PlayerSymbol) {
-        self.positions
+    fn set_collapse(
+        &mut self,
+        field_coordinate: &FieldCoordinate,
+        player_symbol: PlayerSymbol,
+        turn: usize,
+    ) {
+        let field = FieldCoordinate::into_usize(*field_coordinate, self.size);
+        self.hash ^= self.field_marks_hash(field, field)
+            ^ zobrist::collapsed_key(field, player_symbol, turn);
+        self.collapsed
             .set(
                 field_coordinate.y,
                 field_coordinate.x,
-                Field::Collapsed(player_symbol),
+                Some((player_symbol, turn)),
             )
             .expect("Coordinate should be valid");
     }
 
+    fn clear_collapse(&mut self, field_coordinate: &FieldCoordinate) {
+        let field = FieldCoordinate::into_usize(*field_coordinate, self.size);
+        if let Some((player_symbol, turn)) =
+            self.collapsed[(field_coordinate.y, field_coordinate.x)]
+        {
+            self.hash ^= self.field_marks_hash(field, field)
+                ^ zobrist::collapsed_key(field, player_symbol, turn);
+        }
+        self.collapsed
+            .set(field_coordinate.y, field_coordinate.x, None)
+            .expect("Coordinate should be valid");
+    }
+
+    fn set_last_cycle(&mut self, last_cycle: Option<Cycle>) {
+        for cycle in [&self.last_cycle, &last_cycle].into_iter().flatten() {
+            for field_coordinate in cycle.get_fields_coordinate() {
+                self.hash ^= zobrist::cycle_key(field_coordinate.into_usize(self.size));
+            }
+        }
+        self.last_cycle = last_cycle;
+    }
+
+    /// Zobrist hash of the board.
+    pub(super) fn get_hash(&self) -> u64 {
+        self.hash
+    }
+
+    /// Zobrist hash the board would have after the symmetry was applied.
+    pub(super) fn hash_with(&self, symmetry: Symmetry) -> u64 {
+        let hash_field = |field_coordinate: FieldCoordinate| {
+            symmetry
+                .apply(field_coordinate, self.size)
+                .into_usize(self.size)
+        };
+        let fields_hash = (0..self.size * self.size)
+            .map(|field| {
+                let field_coordinate = FieldCoordinate::from_usize(field, self.size);
+                match self.collapsed[(field_coordinate.y, field_coordinate.x)] {
+                    Some((player_symbol, turn)) => {
+                        zobrist::collapsed_key(hash_field(field_coordinate), player_symbol, turn)
+                    }
+                    None => self.field_marks_hash(field, hash_field(field_coordinate)),
+                }
+            })
+            .fold(0, |hash, key| hash ^ key);
+        self.last_cycle
+            .iter()
+            .flat_map(Cycle::get_fields_coordinate)
+            .fold(fields_hash, |hash, &field_coordinate| {
+                hash ^ zobrist::cycle_key(hash_field(field_coordinate))
+            })
+    }
+
+    /// Removes the entanglement between the fields, returning it as nodes and
+    /// turn if there was one.
     fn remove_edge(
         &mut self,
         first_coordinate: &FieldCoordinate,
         second_coordinate: &FieldCoordinate,
-    ) {
-        if let Some(edge) = self.connections.find_edge(
+    ) -> Option<(usize, usize, usize)> {
+        let edge = self.connections.find_edge(
             self.get_node(first_coordinate),
             self.get_node(second_coordinate),
-        ) {
-            self.connections.remove_edge(edge);
-        };
+        )?;
+        let (source, target) = self.connections.edge_endpoints(edge)?;
+        let turn = self.connections.remove_edge(edge)?;
+        Some((source.index(), target.index(), turn))
     }
 
     fn get_node(&self, field_coordinate: &FieldCoordinate) -> NodeIndex {
@@ -274,82 +719,122 @@ impl Board {
     pub(super) fn check_all_lines(&self) -> LinesResult {
         let mut lines_result = LinesResult::new();
         self.check_rows()
-            .iter()
-            .for_each(|&player_symbol| lines_result.increase(player_symbol));
-        self.check_columns()
-            .iter()
-            .for_each(|&player_symbol| lines_result.increase(player_symbol));
-        self.check_diagonals()
-            .iter()
-            .for_each(|&player_symbol| lines_result.increase(player_symbol));
+            .into_iter()
+            .chain(self.check_columns())
+            .chain(self.check_diagonals())
+            .for_each(|line| lines_result.add_line(line));
         lines_result
     }
 
-    fn check_rows(&self) -> Vec<PlayerSymbol> {
-        (0..self.positions.row_len())
-            .filter_map(|row| self.check_row(row))
-            .collect::<Vec<PlayerSymbol>>()
+    fn check_rows(&self) -> Vec<Line> {
+        (0..self.collapsed.row_len())
+            .flat_map(|row| self.check_row(row))
+            .collect::<Vec<_>>()
     }
 
-    fn check_columns(&self) -> Vec<PlayerSymbol> {
-        (0..self.positions.column_len())
-            .filter_map(|column| self.check_column(column))
-            .collect::<Vec<PlayerSymbol>>()
+    fn check_columns(&self) -> Vec<Line> {
+        (0..self.collapsed.column_len())
+            .flat_map(|column| self.check_column(column))
+            .collect::<Vec<_>>()
     }
 
-    fn check_diagonals(&self) -> Vec<PlayerSymbol> {
-        let mut symbols = Vec::new();
-        if let Some(symbol) = self.check_first_diagonal() {
-            symbols.push(symbol);
-        }
-        if let Some(symbol) = self.check_second_diagonal() {
-            symbols.push(symbol);
+    /// Checks every diagonal and anti-diagonal long enough to hold a line.
+    fn check_diagonals(&self) -> Vec<Line> {
+        if self.win_length > self.size {
+            return Vec::new();
         }
-        symbols
+        let last_start = self.size - self.win_length;
+        let diagonals = (0..=last_start)
+            .map(|row| (row, 0))
+            .chain((1..=last_start).map(|column| (0, column)));
+        let anti_diagonals = (0..=last_start)
+            .map(|row| (row, self.size - 1))
+            .chain((self.win_length - 1..self.size - 1).map(|column| (0, column)));
+        diagonals
+            .flat_map(|start| self.check_diagonal(start))
+            .chain(anti_diagonals.flat_map(|start| self.check_anti_diagonal(start)))
+            .collect::<Vec<_>>()
     }
 
-    fn check_row(&self, row: usize) -> Option<PlayerSymbol> {
-        let iter = self
-            .positions
-            .row_iter(row)
-            .expect("Row number should be valid")
-            .peekable();
-        Board::check_line(iter)
+    fn check_row(&self, row: usize) -> Vec<Line> {
+        self.check_line(
+            LineDirection::Row(row),
+            (0..self.size).map(|x| FieldCoordinate { x, y: row }),
+        )
     }
 
-    fn check_column(&self, column: usize) -> Option<PlayerSymbol> {
-        let iter = self
-            .positions
-            .column_iter(column)
-            .expect("Column number should be valid")
-            .peekable();
-        Board::check_line(iter)
+    fn check_column(&self, column: usize) -> Vec<Line> {
+        self.check_line(
+            LineDirection::Column(column),
+            (0..self.size).map(|y| FieldCoordinate { x: column, y }),
+        )
     }
 
-    fn check_first_diagonal(&self) -> Option<PlayerSymbol> {
-        Board::check_line((0..self.size).map(|i| &self.positions[(i, i)]).peekable())
+    /// Checks the diagonal going down and right from `(row, column)`.
+    fn check_diagonal(&self, (row, column): (usize, usize)) -> Vec<Line> {
+        let length = self.size - row.max(column);
+        let offset = isize::try_from(column).expect("Column should fit in isize")
+            - isize::try_from(row).expect("Row should fit in isize");
+        self.check_line(
+            LineDirection::Diagonal(offset),
+            (0..length).map(|i| FieldCoordinate {
+                x: column + i,
+                y: row + i,
+            }),
+        )
     }
 
-    fn check_second_diagonal(&self) -> Option<PlayerSymbol> {
-        Board::check_line(
-            (0..self.size)
-                .map(|i| &self.positions[(i, self.size - i - 1)])
-                .peekable(),
+    /// Checks the diagonal going down and left from `(row, column)`.
+    fn check_anti_diagonal(&self, (row, column): (usize, usize)) -> Vec<Line> {
+        let length = (self.size - row).min(column + 1);
+        self.check_line(
+            LineDirection::AntiDiagonal(row + column),
+            (0..length).map(|i| FieldCoordinate {
+                x: column - i,
+                y: row + i,
+            }),
         )
     }
 
-    fn check_line<'a, I>(mut line: Peekable<I>) -> Option<PlayerSymbol>
+    /// Returns every run of at least `win_length` fields collapsed to one
+    /// player. Each run is reported with the highest turn subscript of its
+    /// earliest completed segment of `win_length` fields.
+    fn check_line<I>(&self, direction: LineDirection, line: I) -> Vec<Line>
     where
-        I: Iterator<Item = &'a Field>,
+        I: Iterator<Item = FieldCoordinate>,
     {
-        let first = *line.peek().expect("Should bo item to pick");
-        if matches!(first, Field::Entangled(_)) {
-            return None;
-        } else if line.all(|field| field == first) {
-            if let Field::Collapsed(symbol) = first {
-                return Some(*symbol);
+        let mut lines = Vec::new();
+        let mut run: Vec<(FieldCoordinate, usize)> = Vec::new();
+        let mut run_symbol = None;
+        for field_coordinate in line.map(Some).chain(iter::once(None)) {
+            let collapsed = field_coordinate.and_then(|field_coordinate| {
+                self.collapsed[(field_coordinate.y, field_coordinate.x)]
+                    .map(|(player_symbol, turn)| (field_coordinate, player_symbol, turn))
+            });
+            if collapsed.map(|(_, player_symbol, _)| player_symbol) != run_symbol {
+                if let Some(player_symbol) = run_symbol {
+                    let turns = run.iter().map(|&(_, turn)| turn).collect::<Vec<_>>();
+                    if let Some(turn) = self.first_segment_turn(&turns) {
+                        let fields = run.iter().map(|&(field, _)| field).collect();
+                        lines.push(Line::new(direction, player_symbol, fields, turn));
+                    }
+                }
+                run.clear();
+                run_symbol = collapsed.map(|(_, player_symbol, _)| player_symbol);
+            }
+            if let Some((field_coordinate, _, turn)) = collapsed {
+                run.push((field_coordinate, turn));
             }
         }
-        None
+        lines
+    }
+
+    fn first_segment_turn(&self, run: &[usize]) -> Option<usize> {
+        if self.win_length == 0 {
+            return None;
+        }
+        run.windows(self.win_length)
+            .map(|segment| *segment.iter().max().expect("Segment should not be empty"))
+            .min()
     }
 }
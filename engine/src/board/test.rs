@@ -1,10 +1,12 @@
 use super::*;
+use crate::rules::{Rules, StandardRules};
+use crate::score::Score;
 
 #[test]
 fn empty_board_3x3() {
     let board = Board::new(3);
     assert_eq!(
-        board.positions,
+        board.get_positions(),
         Array2D::filled_with(Field::Entangled(vec![None; 9]), 3, 3)
     );
 }
@@ -13,7 +15,7 @@ fn empty_board_3x3() {
 fn default_board() {
     let board = Board::new(3);
     let default_board = Board::default();
-    assert_eq!(board.positions, default_board.positions);
+    assert_eq!(board.get_positions(), default_board.get_positions());
     assert_eq!(board.size, default_board.size);
 }
 
@@ -30,7 +32,7 @@ fn first_mark() {
     field.extend([None; 8]);
     array.set(0, 0, Field::Entangled(field.clone())).unwrap();
     array.set(0, 1, Field::Entangled(field)).unwrap();
-    assert_eq!(board.positions, array);
+    assert_eq!(board.get_positions(), array);
 }
 
 #[test]
@@ -59,10 +61,7 @@ fn mark_out_of_band() {
 #[test]
 fn mark_on_collapsed() {
     let mut board = Board::new(3);
-    board
-        .positions
-        .set(0, 0, Field::Collapsed(PlayerSymbol::X))
-        .unwrap();
+    board.set_collapse(&FieldCoordinate { x: 0, y: 0 }, PlayerSymbol::X, 0);
     let fields_coordinates = &[
         FieldCoordinate { x: 0, y: 0 },
         FieldCoordinate { x: 0, y: 1 },
@@ -73,10 +72,7 @@ fn mark_on_collapsed() {
 #[test]
 fn same_fields_coordinates() {
     let mut board = Board::new(3);
-    board
-        .positions
-        .set(0, 0, Field::Collapsed(PlayerSymbol::X))
-        .unwrap();
+    board.set_collapse(&FieldCoordinate { x: 0, y: 0 }, PlayerSymbol::X, 0);
     let fields_coordinates = &[
         FieldCoordinate { x: 0, y: 0 },
         FieldCoordinate { x: 0, y: 0 },
@@ -120,7 +116,7 @@ fn simply_cycle() {
 fn check_row_none() {
     let board = Board::new(3);
     for i in 0..3 {
-        assert!(board.check_row(i).is_none());
+        assert!(board.check_row(i).is_empty());
     }
 }
 
@@ -128,7 +124,7 @@ fn check_row_none() {
 fn check_column_none() {
     let board = Board::new(3);
     for i in 0..3 {
-        assert!(board.check_column(i).is_none());
+        assert!(board.check_column(i).is_empty());
     }
 }
 
@@ -136,57 +132,60 @@ fn check_column_none() {
 fn check_row() {
     let mut board = Board::new(3);
     let mut board_positions = vec![
-        Field::Collapsed(PlayerSymbol::X),
-        Field::Collapsed(PlayerSymbol::X),
-        Field::Collapsed(PlayerSymbol::X),
+        Field::Collapsed(PlayerSymbol::X, 0),
+        Field::Collapsed(PlayerSymbol::X, 4),
+        Field::Collapsed(PlayerSymbol::X, 2),
     ];
     board_positions.extend(vec![Field::Entangled(vec![None; 3 * 3]); 6]);
     let board_positions = Array2D::from_row_major(&board_positions, 3, 3).unwrap();
-    board.positions = board_positions;
-    assert_eq!(board.check_row(0).unwrap(), PlayerSymbol::X);
-    assert!(board.check_row(1).is_none());
-    assert!(board.check_row(2).is_none());
+    board.set_positions(&board_positions);
+    assert_eq!(summary(&board.check_row(0)), vec![(PlayerSymbol::X, 4)]);
+    assert!(board.check_row(1).is_empty());
+    assert!(board.check_row(2).is_empty());
 }
 
 #[test]
 fn check_column() {
     let mut board = Board::new(3);
     let board_positions = vec![
-        Field::Collapsed(PlayerSymbol::X),
+        Field::Collapsed(PlayerSymbol::X, 0),
         Field::Entangled(vec![None; 3 * 3]),
         Field::Entangled(vec![None; 3 * 3]),
-        Field::Collapsed(PlayerSymbol::X),
+        Field::Collapsed(PlayerSymbol::X, 0),
         Field::Entangled(vec![None; 3 * 3]),
         Field::Entangled(vec![None; 3 * 3]),
-        Field::Collapsed(PlayerSymbol::X),
+        Field::Collapsed(PlayerSymbol::X, 0),
         Field::Entangled(vec![None; 3 * 3]),
         Field::Entangled(vec![None; 3 * 3]),
     ];
     let board_positions = Array2D::from_row_major(&board_positions, 3, 3).unwrap();
-    board.positions = board_positions;
-    assert_eq!(board.check_column(0).unwrap(), PlayerSymbol::X);
-    assert!(board.check_column(1).is_none());
-    assert!(board.check_column(2).is_none());
+    board.set_positions(&board_positions);
+    assert_eq!(summary(&board.check_column(0)), vec![(PlayerSymbol::X, 0)]);
+    assert!(board.check_column(1).is_empty());
+    assert!(board.check_column(2).is_empty());
 }
 
 #[test]
 fn check_first_diagonal() {
     let mut board = Board::new(3);
     let board_positions = vec![
-        Field::Collapsed(PlayerSymbol::X),
+        Field::Collapsed(PlayerSymbol::X, 0),
         Field::Entangled(vec![None; 3 * 3]),
         Field::Entangled(vec![None; 3 * 3]),
         Field::Entangled(vec![None; 3 * 3]),
-        Field::Collapsed(PlayerSymbol::X),
+        Field::Collapsed(PlayerSymbol::X, 0),
         Field::Entangled(vec![None; 3 * 3]),
         Field::Entangled(vec![None; 3 * 3]),
         Field::Entangled(vec![None; 3 * 3]),
-        Field::Collapsed(PlayerSymbol::X),
+        Field::Collapsed(PlayerSymbol::X, 0),
     ];
     let board_positions = Array2D::from_row_major(&board_positions, 3, 3).unwrap();
-    board.positions = board_positions;
-    assert_eq!(board.check_first_diagonal().unwrap(), PlayerSymbol::X);
-    assert!(board.check_second_diagonal().is_none());
+    board.set_positions(&board_positions);
+    assert_eq!(
+        summary(&board.check_diagonal((0, 0))),
+        vec![(PlayerSymbol::X, 0)]
+    );
+    assert!(board.check_anti_diagonal((0, 2)).is_empty());
 }
 
 #[test]
@@ -195,18 +194,53 @@ fn check_second_diagonal() {
     let board_positions = vec![
         Field::Entangled(vec![None; 3 * 3]),
         Field::Entangled(vec![None; 3 * 3]),
-        Field::Collapsed(PlayerSymbol::X),
+        Field::Collapsed(PlayerSymbol::X, 0),
         Field::Entangled(vec![None; 3 * 3]),
-        Field::Collapsed(PlayerSymbol::X),
+        Field::Collapsed(PlayerSymbol::X, 0),
         Field::Entangled(vec![None; 3 * 3]),
-        Field::Collapsed(PlayerSymbol::X),
+        Field::Collapsed(PlayerSymbol::X, 0),
         Field::Entangled(vec![None; 3 * 3]),
         Field::Entangled(vec![None; 3 * 3]),
     ];
     let board_positions = Array2D::from_row_major(&board_positions, 3, 3).unwrap();
-    board.positions = board_positions;
-    assert_eq!(board.check_second_diagonal().unwrap(), PlayerSymbol::X);
-    assert!(board.check_first_diagonal().is_none());
+    board.set_positions(&board_positions);
+    assert_eq!(
+        summary(&board.check_anti_diagonal((0, 2))),
+        vec![(PlayerSymbol::X, 0)]
+    );
+    assert!(board.check_diagonal((0, 0)).is_empty());
+}
+
+#[test]
+fn completed_line_details() {
+    let mut board = Board::with_win_length(4, 3);
+    for (x, y, player_symbol, turn) in [
+        (3, 0, PlayerSymbol::O, 1),
+        (2, 1, PlayerSymbol::O, 5),
+        (1, 2, PlayerSymbol::O, 3),
+        (0, 1, PlayerSymbol::X, 0),
+        (1, 1, PlayerSymbol::X, 2),
+    ] {
+        board.set_collapse(&FieldCoordinate { x, y }, player_symbol, turn);
+    }
+    let lines = board.check_all_lines();
+    assert_eq!(
+        lines.get_lines(),
+        &[Line::new(
+            LineDirection::AntiDiagonal(3),
+            PlayerSymbol::O,
+            vec![
+                FieldCoordinate { x: 3, y: 0 },
+                FieldCoordinate { x: 2, y: 1 },
+                FieldCoordinate { x: 1, y: 2 },
+            ],
+            5,
+        )]
+    );
+    assert_eq!(
+        lines.get_lines()[0].get_direction(),
+        LineDirection::AntiDiagonal(3)
+    );
 }
 
 #[test]
@@ -221,12 +255,12 @@ fn simply_collapsed_positions() {
     let _ = board.collapse(FieldCoordinate { x: 0, y: 0 }, 0);
 
     let mut board_positions = vec![
-        Field::Collapsed(PlayerSymbol::X),
-        Field::Collapsed(PlayerSymbol::O),
+        Field::Collapsed(PlayerSymbol::X, 0),
+        Field::Collapsed(PlayerSymbol::O, 1),
     ];
     board_positions.extend(vec![Field::Entangled(vec![None; 3 * 3]); 7]);
     let board_positions = Array2D::from_row_major(&board_positions, 3, 3).unwrap();
-    assert_eq!(board.positions, board_positions);
+    assert_eq!(board.get_positions(), board_positions);
 }
 
 #[test]
@@ -261,14 +295,285 @@ fn simply_collapsed_with_additional_edge() {
     let _ = board.collapse(FieldCoordinate { x: 0, y: 0 }, 2);
 
     let mut board_positions = vec![
-        Field::Collapsed(PlayerSymbol::X),
-        Field::Collapsed(PlayerSymbol::O),
-        Field::Collapsed(PlayerSymbol::X),
+        Field::Collapsed(PlayerSymbol::X, 2),
+        Field::Collapsed(PlayerSymbol::O, 1),
+        Field::Collapsed(PlayerSymbol::X, 0),
     ];
     board_positions.extend(vec![Field::Entangled(vec![None; 3 * 3]); 6]);
     let board_positions = Array2D::from_row_major(&board_positions, 3, 3).unwrap();
-    assert_eq!(board.positions, board_positions);
+    assert_eq!(board.get_positions(), board_positions);
     let connections: Graph<(), usize, Undirected> =
         Graph::from_elements(iter::repeat_n(Element::Node { weight: () }, 3 * 3));
     assert_eq!(connections.edge_count(), board.connections.edge_count());
 }
+
+#[test]
+fn score_single_line() {
+    let mut board = Board::new(3);
+    let mut board_positions = vec![
+        Field::Collapsed(PlayerSymbol::O, 1),
+        Field::Collapsed(PlayerSymbol::O, 5),
+        Field::Collapsed(PlayerSymbol::O, 3),
+    ];
+    board_positions.extend(vec![Field::Entangled(vec![None; 3 * 3]); 6]);
+    board.set_positions(&Array2D::from_row_major(&board_positions, 3, 3).unwrap());
+    let lines_result = board.check_all_lines();
+    assert!(!lines_result.get_lines().is_empty());
+    assert_eq!(
+        StandardRules.score(lines_result.get_lines()),
+        Score::new(0, 2)
+    );
+}
+
+#[test]
+fn score_simultaneous_lines() {
+    let mut board = Board::new(3);
+    let mut board_positions = vec![
+        Field::Collapsed(PlayerSymbol::X, 0),
+        Field::Collapsed(PlayerSymbol::X, 6),
+        Field::Collapsed(PlayerSymbol::X, 2),
+        Field::Collapsed(PlayerSymbol::O, 1),
+        Field::Collapsed(PlayerSymbol::O, 3),
+        Field::Collapsed(PlayerSymbol::O, 5),
+    ];
+    board_positions.extend(vec![Field::Entangled(vec![None; 3 * 3]); 3]);
+    board.set_positions(&Array2D::from_row_major(&board_positions, 3, 3).unwrap());
+    let lines_result = board.check_all_lines();
+    assert_eq!(
+        StandardRules.score(lines_result.get_lines()),
+        Score::new(1, 2)
+    );
+    assert_eq!(
+        StandardRules.score(lines_result.get_lines()).winner(),
+        Some(PlayerSymbol::O)
+    );
+}
+
+#[test]
+fn score_double_line() {
+    let mut board = Board::new(3);
+    let board_positions = vec![
+        Field::Collapsed(PlayerSymbol::X, 0),
+        Field::Collapsed(PlayerSymbol::X, 2),
+        Field::Collapsed(PlayerSymbol::X, 4),
+        Field::Collapsed(PlayerSymbol::O, 1),
+        Field::Collapsed(PlayerSymbol::X, 6),
+        Field::Entangled(vec![None; 3 * 3]),
+        Field::Collapsed(PlayerSymbol::O, 3),
+        Field::Entangled(vec![None; 3 * 3]),
+        Field::Collapsed(PlayerSymbol::X, 8),
+    ];
+    board.set_positions(&Array2D::from_row_major(&board_positions, 3, 3).unwrap());
+    let lines_result = board.check_all_lines();
+    assert_eq!(
+        StandardRules.score(lines_result.get_lines()),
+        Score::new(4, 0)
+    );
+}
+
+#[test]
+fn collapse_against_cycle_direction() {
+    let mut board = Board::new(3);
+    let first = FieldCoordinate { x: 0, y: 0 };
+    let second = FieldCoordinate { x: 1, y: 0 };
+    let third = FieldCoordinate { x: 2, y: 0 };
+    let _ = board.mark(&[first, second], PlayerSymbol::X, 0);
+    let _ = board.mark(&[second, third], PlayerSymbol::O, 1);
+    let _ = board.mark(&[third, first], PlayerSymbol::X, 2);
+    board.collapse(first, 0).unwrap();
+
+    let mut board_positions = vec![
+        Field::Collapsed(PlayerSymbol::X, 0),
+        Field::Collapsed(PlayerSymbol::O, 1),
+        Field::Collapsed(PlayerSymbol::X, 2),
+    ];
+    board_positions.extend(vec![Field::Entangled(vec![None; 3 * 3]); 6]);
+    let board_positions = Array2D::from_row_major(&board_positions, 3, 3).unwrap();
+    assert_eq!(board.get_positions(), board_positions);
+}
+
+#[test]
+fn win_length_row() {
+    let mut board = Board::with_win_length(5, 3);
+    let mut board_positions = vec![
+        Field::Entangled(vec![None; 5 * 5]),
+        Field::Collapsed(PlayerSymbol::O, 7),
+        Field::Collapsed(PlayerSymbol::O, 1),
+        Field::Collapsed(PlayerSymbol::O, 3),
+        Field::Collapsed(PlayerSymbol::O, 5),
+    ];
+    board_positions.extend(vec![Field::Entangled(vec![None; 5 * 5]); 20]);
+    board.set_positions(&Array2D::from_row_major(&board_positions, 5, 5).unwrap());
+    assert_eq!(summary(&board.check_row(0)), vec![(PlayerSymbol::O, 5)]);
+    assert_eq!(
+        StandardRules.score(board.check_all_lines().get_lines()),
+        Score::new(0, 2)
+    );
+}
+
+#[test]
+fn win_length_short_run() {
+    let mut board = Board::with_win_length(5, 3);
+    let mut board_positions = vec![
+        Field::Collapsed(PlayerSymbol::X, 0),
+        Field::Collapsed(PlayerSymbol::X, 2),
+        Field::Collapsed(PlayerSymbol::O, 1),
+        Field::Collapsed(PlayerSymbol::X, 4),
+        Field::Collapsed(PlayerSymbol::X, 6),
+    ];
+    board_positions.extend(vec![Field::Entangled(vec![None; 5 * 5]); 20]);
+    board.set_positions(&Array2D::from_row_major(&board_positions, 5, 5).unwrap());
+    assert!(board.check_row(0).is_empty());
+    assert!(board.check_all_lines().get_lines().is_empty());
+}
+
+#[test]
+fn win_length_short_diagonals() {
+    let mut board = Board::with_win_length(5, 3);
+    let mut board_positions = vec![Field::Entangled(vec![None; 5 * 5]); 25];
+    for (row, column) in [(2, 0), (3, 1), (4, 2)] {
+        board_positions[row * 5 + column] = Field::Collapsed(PlayerSymbol::X, row);
+    }
+    for (row, column) in [(0, 3), (1, 2), (2, 1)] {
+        board_positions[row * 5 + column] = Field::Collapsed(PlayerSymbol::O, 5 + row);
+    }
+    board.set_positions(&Array2D::from_row_major(&board_positions, 5, 5).unwrap());
+    assert_eq!(
+        summary(&board.check_diagonals()),
+        vec![(PlayerSymbol::X, 4), (PlayerSymbol::O, 7)]
+    );
+    assert_eq!(
+        board
+            .check_diagonals()
+            .iter()
+            .map(Line::get_direction)
+            .collect::<Vec<_>>(),
+        vec![LineDirection::Diagonal(-2), LineDirection::AntiDiagonal(3)]
+    );
+    assert_eq!(
+        StandardRules.score(board.check_all_lines().get_lines()),
+        Score::new(2, 1)
+    );
+}
+
+#[test]
+fn render_board() {
+    let board = board_to_render();
+    let expected = [
+        "     a       b       c",
+        "   X1    | X1 O2 |",
+        "1        | X3 O4 |",
+        "         |       |",
+        "  -------+-------+-------",
+        "   /-\\   | X3    | \\ /",
+        "2  | |   |       |  X",
+        "   \\-/6  |       | / \\5",
+        "  -------+-------+-------",
+        "   O4    |       | O2",
+        "3        |       |",
+        "         |       |",
+    ]
+    .join("\n");
+    assert_eq!(board.to_string(), expected);
+}
+
+#[test]
+fn render_board_without_labels() {
+    let board = board_to_render();
+    let expected = [
+        " X0    | X0 O1 |",
+        "       | X2 O3 |",
+        "       |       |",
+        "-------+-------+-------",
+        " /-\\   | X2    | \\ /",
+        " | |   |       |  X",
+        " \\-/5  |       | / \\4",
+        "-------+-------+-------",
+        " O3    |       | O1",
+        "       |       |",
+        "       |       |",
+    ]
+    .join("\n");
+    let renderer = BoardRenderer::default()
+        .with_first_subscript(0)
+        .with_labels(false);
+    assert_eq!(renderer.render(&board), expected);
+}
+
+#[test]
+fn render_cycle() {
+    let mut board = board_to_render();
+    let cycle = board
+        .mark(
+            &[
+                FieldCoordinate { x: 0, y: 0 },
+                FieldCoordinate { x: 2, y: 2 },
+            ],
+            PlayerSymbol::X,
+            6,
+        )
+        .unwrap()
+        .unwrap();
+    assert_eq!(cycle.to_string(), "a1 (1, 7) - b1 (1, 2) - c3 (2, 7)");
+}
+
+#[test]
+fn field_accessors_match_positions() {
+    let mut board = board_to_render();
+    let _ = board.mark(
+        &[
+            FieldCoordinate { x: 1, y: 1 },
+            FieldCoordinate { x: 0, y: 0 },
+        ],
+        PlayerSymbol::X,
+        6,
+    );
+    board.collapse(FieldCoordinate { x: 0, y: 0 }, 0).unwrap();
+    let positions = board.get_positions();
+    for index in 0..9 {
+        let field_coordinate = FieldCoordinate::from_usize(index, 3);
+        let field = &positions[(field_coordinate.y, field_coordinate.x)];
+        let collapsed = match field {
+            Field::Collapsed(player_symbol, turn) => Some((*player_symbol, *turn)),
+            Field::Entangled(_) => None,
+        };
+        assert_eq!(board.get_collapsed(field_coordinate), collapsed);
+        for player_symbol in [PlayerSymbol::X, PlayerSymbol::O] {
+            let has_mark = matches!(
+                field,
+                Field::Entangled(symbols) if symbols.contains(&Some(player_symbol))
+            );
+            assert_eq!(board.has_mark(field_coordinate, player_symbol), has_mark);
+        }
+    }
+}
+
+fn board_to_render() -> Board {
+    let mut board = Board::new(3);
+    let field_coordinate = |x, y| FieldCoordinate { x, y };
+    let marks = [
+        ((0, 0), (1, 0), PlayerSymbol::X),
+        ((1, 0), (2, 2), PlayerSymbol::O),
+        ((1, 0), (1, 1), PlayerSymbol::X),
+        ((1, 0), (0, 2), PlayerSymbol::O),
+    ];
+    for (turn, ((x1, y1), (x2, y2), player_symbol)) in marks.into_iter().enumerate() {
+        board
+            .mark(
+                &[field_coordinate(x1, y1), field_coordinate(x2, y2)],
+                player_symbol,
+                turn,
+            )
+            .unwrap();
+    }
+    board.set_collapse(&field_coordinate(2, 1), PlayerSymbol::X, 4);
+    board.set_collapse(&field_coordinate(0, 1), PlayerSymbol::O, 5);
+    board
+}
+
+fn summary(lines: &[Line]) -> Vec<(PlayerSymbol, usize)> {
+    lines
+        .iter()
+        .map(|line| (line.get_player_symbol(), line.get_max_turn()))
+        .collect()
+}
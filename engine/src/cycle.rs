@@ -1,5 +1,8 @@
+use crate::board::board_renderer::BoardRenderer;
 use crate::field_coordinate::FieldCoordinate;
 use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::fmt::Formatter;
 
 #[derive(Clone, Serialize, Debug, PartialEq, Deserialize)]
 pub struct Cycle {
@@ -18,29 +21,82 @@ impl Cycle {
         }
     }
 
-    pub(super) fn get_fields_coordinate(&self) -> &[FieldCoordinate] {
+    #[must_use]
+    pub fn get_fields_coordinate(&self) -> &[FieldCoordinate] {
         &self.fields_coordinates
     }
 
-    pub(super) fn get_fields_indexes(&self) -> &[Vec<usize>] {
+    /// Turns of the marks each field of the cycle can collapse to.
+    #[must_use]
+    pub fn get_fields_indexes(&self) -> &[Vec<usize>] {
         &self.fields_indexes
     }
 
-    pub(super) fn len(&self) -> usize {
+    #[must_use]
+    pub fn len(&self) -> usize {
         self.fields_coordinates.len()
     }
 
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.fields_coordinates.is_empty()
+    }
+
     pub(super) fn shift(&mut self, n: usize) {
         self.fields_coordinates.rotate_left(n);
         self.fields_indexes.rotate_left(n);
     }
 
+    /// Reverses the direction of the cycle, keeping the first field in place.
+    pub(super) fn reverse(&mut self) {
+        self.fields_coordinates[1..].reverse();
+        self.fields_indexes[1..].reverse();
+    }
+
+    /// Turns of the marks joining each field of the cycle to the next one,
+    /// None if the indexes of the fields don't close a cycle.
+    pub(crate) fn edge_turns(&self) -> Option<Vec<usize>> {
+        let len = self.len();
+        if len < 2
+            || self.fields_indexes.len() != len
+            || self
+                .fields_indexes
+                .iter()
+                .any(|indexes| indexes.len() != 2 || indexes[0] == indexes[1])
+        {
+            return None;
+        }
+        let turns = [0, 1].into_iter().find_map(|first| {
+            let mut turns = vec![self.fields_indexes[0][first]];
+            for indexes in &self.fields_indexes[1..] {
+                let previous = turns[turns.len() - 1];
+                let next = match indexes[..] {
+                    [turn, next] | [next, turn] if turn == previous => next,
+                    _ => return None,
+                };
+                turns.push(next);
+            }
+            (turns[len - 1] == self.fields_indexes[0][1 - first]).then_some(turns)
+        })?;
+        let mut distinct = turns.clone();
+        distinct.sort_unstable();
+        distinct.dedup();
+        (distinct.len() == len).then_some(turns)
+    }
+
     pub(super) fn remove(&mut self, n: usize, edge_weight: usize) -> usize {
         self.fields_indexes[n].retain(|&x| x != edge_weight);
         self.fields_indexes[n][0]
     }
 
-    pub(super) fn get_field_coordinate(&self, n: usize) -> &FieldCoordinate {
+    #[must_use]
+    pub fn get_field_coordinate(&self, n: usize) -> &FieldCoordinate {
         &self.fields_coordinates[n]
     }
 }
+
+impl fmt::Display for Cycle {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", BoardRenderer::default().render_cycle(self))
+    }
+}
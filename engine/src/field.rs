@@ -4,5 +4,6 @@ use serde::{Deserialize, Serialize};
 #[derive(Clone, PartialEq, Serialize, Debug, Deserialize)]
 pub enum Field {
     Entangled(Vec<Option<PlayerSymbol>>),
-    Collapsed(PlayerSymbol),
+    /// Symbol and turn of the mark the field collapsed to.
+    Collapsed(PlayerSymbol, usize),
 }
@@ -1,4 +1,9 @@
+use crate::notation::notation_error::NotationError;
+use error_stack::{Report, Result};
 use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::fmt::Formatter;
+use std::str::FromStr;
 
 #[derive(Clone, Copy, Deserialize, Serialize, Debug, PartialEq, Hash, Eq)]
 pub struct FieldCoordinate {
@@ -7,10 +12,12 @@ pub struct FieldCoordinate {
 }
 
 impl FieldCoordinate {
+    #[must_use]
     pub fn into_usize(self, size: usize) -> usize {
         self.y * size + self.x
     }
 
+    #[must_use]
     pub fn from_usize(value: usize, size: usize) -> FieldCoordinate {
         FieldCoordinate {
             x: value % size,
@@ -18,3 +25,55 @@ impl FieldCoordinate {
         }
     }
 }
+
+/// Column as letters (`a`, `b`, ..., `z`, `aa`, ...) followed by the row
+/// counted from 1, e.g. `b3` for `x: 1, y: 2`.
+impl fmt::Display for FieldCoordinate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", column_name(self.x), self.y + 1)
+    }
+}
+
+impl FromStr for FieldCoordinate {
+    type Err = Report<NotationError>;
+
+    fn from_str(s: &str) -> Result<Self, NotationError> {
+        let error = || {
+            Report::new(NotationError {})
+                .attach_printable(format!("Field {s} should be a column and a row"))
+        };
+        let row_start = s
+            .find(|c: char| c.is_ascii_digit())
+            .filter(|&row_start| row_start > 0)
+            .ok_or_else(error)?;
+        let (column, row) = s.split_at(row_start);
+        let mut x = 0usize;
+        for letter in column.bytes() {
+            if !letter.is_ascii_lowercase() {
+                return Err(error());
+            }
+            x = x
+                .checked_mul(26)
+                .and_then(|x| x.checked_add(usize::from(letter - b'a') + 1))
+                .ok_or_else(error)?;
+        }
+        let y = row.parse::<usize>().map_err(|_| error())?;
+        Ok(FieldCoordinate {
+            x: x - 1,
+            y: y.checked_sub(1).ok_or_else(error)?,
+        })
+    }
+}
+
+pub(crate) fn column_name(x: usize) -> String {
+    let mut letters = Vec::new();
+    let mut column = x + 1;
+    while column > 0 {
+        column -= 1;
+        letters.push(char::from(
+            b'a' + u8::try_from(column % 26).expect("Should be a letter"),
+        ));
+        column /= 26;
+    }
+    letters.iter().rev().collect()
+}
@@ -0,0 +1,44 @@
+use crate::board::Board;
+use crate::field_coordinate::FieldCoordinate;
+use crate::player_move::Move;
+use crate::player_symbol::PlayerSymbol;
+
+/// Outcome of one collapse choice of the pending cycle.
+#[derive(Debug, Clone)]
+pub struct CollapsePreview {
+    player_move: Move,
+    collapsed_fields: Vec<(FieldCoordinate, PlayerSymbol, usize)>,
+    board: Board,
+}
+
+impl CollapsePreview {
+    pub(super) fn new(
+        player_move: Move,
+        collapsed_fields: Vec<(FieldCoordinate, PlayerSymbol, usize)>,
+        board: Board,
+    ) -> Self {
+        CollapsePreview {
+            player_move,
+            collapsed_fields,
+            board,
+        }
+    }
+
+    #[must_use]
+    pub fn get_player_move(&self) -> Move {
+        self.player_move
+    }
+
+    /// Fields collapsed by the move, inside and outside the cycle, with the
+    /// symbol and turn of the mark each one collapses to.
+    #[must_use]
+    pub fn get_collapsed_fields(&self) -> &[(FieldCoordinate, PlayerSymbol, usize)] {
+        &self.collapsed_fields
+    }
+
+    /// Board after the collapse.
+    #[must_use]
+    pub fn get_board(&self) -> &Board {
+        &self.board
+    }
+}
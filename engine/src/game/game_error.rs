@@ -1,3 +1,4 @@
+use crate::board::board_error::BoardErrorKind;
 use core::fmt;
 use std::error::Error;
 use std::fmt::Formatter;
@@ -7,7 +8,18 @@ pub enum GameError {
     PlayerTurnError,
     MoveTypeError,
     MoveAfterEnd,
-    MakingMoveError,
+    /// The board rejected the move.
+    MakingMoveError(BoardErrorKind),
+    NothingToUndo,
+    NothingToRedo,
+    InvalidSnapshot,
+    /// Boards are at least [`MIN_BOARD_SIZE`](crate::MIN_BOARD_SIZE) fields
+    /// wide.
+    InvalidSize,
+    /// Lines can't be shorter than one field or longer than the board.
+    InvalidWinLength,
+    /// The rules didn't end the game once every field was collapsed.
+    MissingGameEnd,
 }
 
 impl fmt::Display for GameError {
@@ -1,10 +1,23 @@
 use crate::cycle::Cycle;
-use crate::player_symbol::PlayerSymbol;
+use crate::line::Line;
+use crate::score::Score;
+use serde::{Deserialize, Serialize};
 
 #[derive(Debug, PartialEq)]
 pub enum GameResult {
     NextTurn,
     TurnAfterCollapse,
     NextTurnCycle(Cycle),
-    GameEnd(Option<PlayerSymbol>),
+    GameEnd(Score, EndReason),
+}
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub enum EndReason {
+    /// At least one line was completed, every completed line is listed.
+    Lines(Vec<Line>),
+    /// Every field collapsed without completing a line, or every field but
+    /// the last one under rules without classical moves.
+    BoardFull,
+    /// The game was ended with [`Game::end_game`](crate::game::Game::end_game).
+    Interrupted,
 }
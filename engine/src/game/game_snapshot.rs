@@ -0,0 +1,22 @@
+use crate::cycle::Cycle;
+use crate::field::Field;
+use crate::field_coordinate::FieldCoordinate;
+use crate::game_status::GameStatus;
+use crate::rules::{CollapseChooser, RuleSet};
+use array2d::Array2D;
+use serde::{Deserialize, Serialize};
+
+/// Complete state of a game in progress, which can be stored and later
+/// restored with [`Game::from_snapshot`](crate::game::Game::from_snapshot).
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct GameSnapshot {
+    pub(super) size: usize,
+    pub(super) win_length: usize,
+    pub(super) positions: Array2D<Field>,
+    pub(super) connections: Vec<(FieldCoordinate, FieldCoordinate, usize)>,
+    pub(super) last_cycle: Option<Cycle>,
+    pub(super) game_status: GameStatus,
+    /// None for a game played by rules not of a [`RuleSet`].
+    pub(super) rule_set: Option<RuleSet>,
+    pub(super) collapse_chooser: CollapseChooser,
+}
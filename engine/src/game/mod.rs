@@ -1,31 +1,256 @@
+pub mod collapse_preview;
 #[allow(clippy::module_name_repetitions)]
 pub mod game_error;
 #[allow(clippy::module_name_repetitions)]
 pub mod game_result;
+#[allow(clippy::module_name_repetitions)]
+pub mod game_snapshot;
 #[cfg(test)]
 mod test;
 
+use crate::board::board_error::BoardError;
 use crate::board::Board;
+use crate::field::Field;
+use crate::game::collapse_preview::CollapsePreview;
 use crate::game::game_error::GameError;
-use crate::game::game_result::GameResult;
+use crate::game::game_result::{EndReason, GameResult};
+use crate::game::game_snapshot::GameSnapshot;
 use crate::game_status::GameStatus;
+use crate::move_type::MoveType;
 use crate::player_move::Move;
 use crate::player_symbol::PlayerSymbol;
+use crate::rules::{CollapseChooser, RuleSet, Rules};
+use crate::score::Score;
+use crate::symmetry::Symmetry;
+use crate::{DEFAULT_BOARD_SIZE, MIN_BOARD_SIZE};
+use array2d::Array2D;
+use std::sync::Arc;
 
-use error_stack::{Report, Result, ResultExt};
+use error_stack::{Report, Result};
 
-#[derive(Default)]
+#[derive(Clone)]
+#[allow(clippy::struct_field_names)]
 pub struct Game {
     board: Board,
     game_status: GameStatus,
+    rules: Arc<dyn Rules>,
+    /// None for rules not of a [`RuleSet`].
+    rule_set: Option<RuleSet>,
+    collapse_chooser: CollapseChooser,
+    undo_history: Vec<UndoEntry>,
+    redo_history: Vec<(Move, PlayerSymbol)>,
+    /// Set by [`Game::end_game`], whose end isn't a move that can be undone.
+    interrupted: bool,
+}
+
+impl Default for Game {
+    fn default() -> Self {
+        Game::new(DEFAULT_BOARD_SIZE)
+    }
+}
+
+/// State of the game from before a move was made. The board keeps its own
+/// record of the changes of every move.
+#[derive(Clone)]
+struct UndoEntry {
+    player_move: Move,
+    player_symbol: PlayerSymbol,
+    game_status: GameStatus,
 }
 
 impl Game {
+    /// # Panics
+    ///
+    /// Will panic if the board is smaller than [`MIN_BOARD_SIZE`].
     #[must_use]
     pub fn new(size: usize) -> Self {
+        Game::with_win_length(size, size).expect("Board should be large enough")
+    }
+
+    /// Game in which `win_length` collapsed fields in a row, column or
+    /// diagonal complete a line.
+    ///
+    /// # Errors
+    ///
+    /// Will return Err if the board is smaller than [`MIN_BOARD_SIZE`] or
+    /// `win_length` is zero or longer than the board.
+    pub fn with_win_length(size: usize, win_length: usize) -> Result<Self, GameError> {
+        Game::check_dimensions(size, win_length)?;
+        Ok(Game::build(
+            size,
+            win_length,
+            RuleSet::Standard.rules(),
+            Some(RuleSet::Standard),
+        ))
+    }
+
+    /// Game played by rules not of a [`RuleSet`]. Its snapshot can only be
+    /// restored with [`Game::from_snapshot_with_rules`].
+    ///
+    /// # Errors
+    ///
+    /// Will return Err if the board is smaller than [`MIN_BOARD_SIZE`] or
+    /// `win_length` is zero or longer than the board.
+    pub fn with_rules(
+        size: usize,
+        win_length: usize,
+        rules: Arc<dyn Rules>,
+    ) -> Result<Self, GameError> {
+        Game::check_dimensions(size, win_length)?;
+        Ok(Game::build(size, win_length, rules, None))
+    }
+
+    fn check_dimensions(size: usize, win_length: usize) -> Result<(), GameError> {
+        if size < MIN_BOARD_SIZE {
+            return Err(
+                Report::new(GameError::InvalidSize).attach_printable(format!(
+                    "Board of size {size} should be at least {MIN_BOARD_SIZE} fields wide"
+                )),
+            );
+        }
+        if !(1..=size).contains(&win_length) {
+            return Err(
+                Report::new(GameError::InvalidWinLength).attach_printable(format!(
+                    "Win length {win_length} should be between 1 and {size}"
+                )),
+            );
+        }
+        Ok(())
+    }
+
+    fn build(
+        size: usize,
+        win_length: usize,
+        rules: Arc<dyn Rules>,
+        rule_set: Option<RuleSet>,
+    ) -> Self {
         Game {
-            board: Board::new(size),
+            board: Board::with_win_length(size, win_length),
             game_status: GameStatus::new(),
+            rules,
+            rule_set,
+            collapse_chooser: CollapseChooser::default(),
+            undo_history: Vec::new(),
+            redo_history: Vec::new(),
+            interrupted: false,
+        }
+    }
+
+    /// Plays the game by a rule variant instead of the standard rules.
+    #[must_use]
+    pub fn with_rule_set(mut self, rule_set: RuleSet) -> Self {
+        self.rules = rule_set.rules();
+        self.rule_set = Some(rule_set);
+        self
+    }
+
+    /// Lets another player than the opponent of the cycle creator choose
+    /// the collapse.
+    #[must_use]
+    pub fn with_collapse_chooser(mut self, collapse_chooser: CollapseChooser) -> Self {
+        self.collapse_chooser = collapse_chooser;
+        self
+    }
+
+    /// Restores a game stored with [`Game::snapshot`] with its rule set and
+    /// collapse chooser. The restored game starts without undo and redo
+    /// history.
+    ///
+    /// # Errors
+    ///
+    /// Will return Err if the snapshot doesn't describe a valid board, the
+    /// move expected doesn't match the pending cycle or the game was played
+    /// by rules not of a [`RuleSet`].
+    pub fn from_snapshot(snapshot: GameSnapshot) -> Result<Self, GameError> {
+        let Some(rule_set) = snapshot.rule_set else {
+            return Err(Report::new(GameError::InvalidSnapshot)
+                .attach_printable("Game played by custom rules should be restored with them"));
+        };
+        Game::restore(snapshot, rule_set.rules(), Some(rule_set))
+    }
+
+    /// Restores a game stored with [`Game::snapshot`] played by rules not of
+    /// a [`RuleSet`].
+    ///
+    /// # Errors
+    ///
+    /// Will return Err if the snapshot doesn't describe a valid board or the
+    /// move expected doesn't match the pending cycle.
+    pub fn from_snapshot_with_rules(
+        snapshot: GameSnapshot,
+        rules: Arc<dyn Rules>,
+    ) -> Result<Self, GameError> {
+        Game::restore(snapshot, rules, None)
+    }
+
+    fn restore(
+        snapshot: GameSnapshot,
+        rules: Arc<dyn Rules>,
+        rule_set: Option<RuleSet>,
+    ) -> Result<Self, GameError> {
+        if Game::check_dimensions(snapshot.size, snapshot.win_length).is_err() {
+            return Err(Report::new(GameError::InvalidSnapshot)
+                .attach_printable("Snapshot has a board that can't be played"));
+        }
+        let is_collapse = snapshot.game_status.get_move_type() == MoveType::Collapse;
+        if is_collapse != snapshot.last_cycle.is_some() {
+            return Err(Report::new(GameError::InvalidSnapshot)
+                .attach_printable("Collapse is expected only with a pending cycle"));
+        }
+        let game_status = &snapshot.game_status;
+        if !game_status.is_consistent()
+            || !marks_match_turn(&snapshot.positions, game_status.get_turn())
+        {
+            return Err(Report::new(GameError::InvalidSnapshot)
+                .attach_printable("Game status doesn't match the marks on the board"));
+        }
+        let Some(board) = Board::from_parts(
+            snapshot.size,
+            snapshot.win_length,
+            &snapshot.positions,
+            &snapshot.connections,
+            snapshot.last_cycle,
+        ) else {
+            return Err(Report::new(GameError::InvalidSnapshot)
+                .attach_printable("Snapshot doesn't describe a valid board"));
+        };
+        let game = Game {
+            board,
+            game_status: snapshot.game_status,
+            rules,
+            rule_set,
+            collapse_chooser: snapshot.collapse_chooser,
+            undo_history: Vec::new(),
+            redo_history: Vec::new(),
+            interrupted: false,
+        };
+        // An ended game may have been interrupted at any position
+        if !game.game_status.is_game_end() {
+            let fields = game.board.get_size() * game.board.get_size();
+            let is_expected = match game.game_status.get_move_type() {
+                MoveType::Mark => game.game_status.get_turn() < fields,
+                MoveType::Collapse => true,
+                MoveType::Classical => game.board.get_entangled_coordinates().len() == 1,
+            };
+            if !is_expected || game.check_end().is_some() {
+                return Err(Report::new(GameError::InvalidSnapshot)
+                    .attach_printable("Unfinished game doesn't fit the board"));
+            }
+        }
+        Ok(game)
+    }
+
+    #[must_use]
+    pub fn snapshot(&self) -> GameSnapshot {
+        GameSnapshot {
+            size: self.board.get_size(),
+            win_length: self.board.get_win_length(),
+            positions: self.board.get_positions(),
+            connections: self.board.get_connections(),
+            last_cycle: self.board.get_last_cycle().cloned(),
+            game_status: self.game_status.clone(),
+            rule_set: self.rule_set,
+            collapse_chooser: self.collapse_chooser,
         }
     }
 
@@ -38,6 +263,59 @@ impl Game {
         player_move: Move,
         player_symbol: PlayerSymbol,
     ) -> Result<GameResult, GameError> {
+        let result = self.apply_move(player_move, player_symbol)?;
+        self.redo_history.clear();
+        Ok(result)
+    }
+
+    /// Takes back the last move, restoring the board and the game status
+    /// exactly as they were before it was made. Only the changes of the move
+    /// are reverted, so [`Game::player_move`] and [`Game::undo`] can be used
+    /// to walk a search tree without copying the game.
+    ///
+    /// # Errors
+    ///
+    /// Will return Err if no move was made yet or the game was ended with
+    /// [`Game::end_game`].
+    pub fn undo(&mut self) -> Result<(Move, PlayerSymbol), GameError> {
+        if self.interrupted {
+            return Err(
+                Report::new(GameError::MoveAfterEnd).attach_printable("Game was interrupted")
+            );
+        }
+        let Some(entry) = self.undo_history.pop() else {
+            return Err(Report::new(GameError::NothingToUndo).attach_printable("No move to undo"));
+        };
+        let unmade = self.board.unmake();
+        debug_assert!(unmade, "Board should have the move to undo");
+        self.game_status = entry.game_status;
+        self.redo_history
+            .push((entry.player_move, entry.player_symbol));
+        Ok((entry.player_move, entry.player_symbol))
+    }
+
+    /// Makes again the last move taken back with [`Game::undo`].
+    ///
+    /// # Errors
+    ///
+    /// Will return Err if there is no move to redo.
+    pub fn redo(&mut self) -> Result<GameResult, GameError> {
+        let Some((player_move, player_symbol)) = self.redo_history.pop() else {
+            return Err(Report::new(GameError::NothingToRedo).attach_printable("No move to redo"));
+        };
+        self.apply_move(player_move, player_symbol)
+    }
+
+    /// Runs every check of [`Game::player_move`] without changing the game.
+    ///
+    /// # Errors
+    ///
+    /// Will return the error [`Game::player_move`] would return for this move.
+    pub fn validate_move(
+        &self,
+        player_move: &Move,
+        player_symbol: PlayerSymbol,
+    ) -> Result<(), GameError> {
         if self.game_status.is_game_end() {
             return Err(Report::new(GameError::MoveAfterEnd).attach_printable("Game already end"));
         }
@@ -46,21 +324,55 @@ impl Game {
                 Report::new(GameError::PlayerTurnError).attach_printable("Not this player turn")
             );
         }
-        if !self.game_status.is_good_move_type(&player_move) {
+        if !self.game_status.is_good_move_type(player_move) {
             return Err(Report::new(GameError::MoveTypeError).attach_printable("Wrong move type"));
         }
+        match *player_move {
+            Move::Mark { field1, field2 } => self.board.validate_mark(&[field1, field2]),
+            Move::Collapse { field, index } => {
+                self.board.validate_collapse(field, index).map(|_| ())
+            }
+            Move::Classical { field } => self.board.validate_classical(field),
+        }
+        .map_err(making_move_error)
+    }
+
+    fn apply_move(
+        &mut self,
+        player_move: Move,
+        player_symbol: PlayerSymbol,
+    ) -> Result<GameResult, GameError> {
+        self.validate_move(&player_move, player_symbol)?;
+
+        let entry = UndoEntry {
+            player_move,
+            player_symbol,
+            game_status: self.game_status.clone(),
+        };
+        // A failed move leaves the board and the game status untouched
+        let result = self.make_move(player_move, player_symbol)?;
+        self.undo_history.push(entry);
+        Ok(result)
+    }
 
+    fn make_move(
+        &mut self,
+        player_move: Move,
+        player_symbol: PlayerSymbol,
+    ) -> Result<GameResult, GameError> {
         match player_move {
             Move::Mark { field1, field2 } => {
+                let turn = self.game_status.get_turn();
                 let cycle = self
                     .board
-                    .mark(
-                        &[field1, field2],
-                        player_symbol,
-                        self.game_status.get_turn(),
-                    )
-                    .change_context(GameError::MakingMoveError)?;
+                    .mark(&[field1, field2], player_symbol, turn)
+                    .map_err(making_move_error)?;
                 self.game_status.next_turn(cycle.is_some());
+                if cycle.is_some() {
+                    self.game_status.set_collapse_player(
+                        self.collapse_chooser.collapse_player(player_symbol, turn),
+                    );
+                }
                 match cycle {
                     Some(cycle) => Ok(GameResult::NextTurnCycle(cycle)),
                     None => Ok(GameResult::NextTurn),
@@ -69,18 +381,130 @@ impl Game {
             Move::Collapse { field, index } => {
                 self.board
                     .collapse(field, index)
-                    .change_context(GameError::MakingMoveError)?;
-                let (is_end, winner) = self.check_end();
-                if is_end {
-                    self.game_status.set_end(winner);
-                    Ok(GameResult::GameEnd(winner))
+                    .map_err(making_move_error)?;
+                self.game_status.next_turn(false);
+                if let Some((score, reason)) = self.check_end() {
+                    self.game_status.set_end(score.winner());
+                    Ok(GameResult::GameEnd(score, reason))
                 } else {
+                    if self.board.get_entangled_coordinates().len() == 1
+                        && self.rules.allows_classical()
+                    {
+                        self.game_status.set_classical();
+                    }
                     Ok(GameResult::TurnAfterCollapse)
                 }
             }
+            Move::Classical { field } => {
+                self.board
+                    .classical(field, player_symbol, self.game_status.get_turn())
+                    .map_err(making_move_error)?;
+                let Some((score, reason)) = self.check_end() else {
+                    // No move is left after the last field, the rules are
+                    // broken and the move is taken back
+                    let unmade = self.board.unmake();
+                    debug_assert!(unmade, "Board should have the classical move");
+                    return Err(Report::new(GameError::MissingGameEnd)
+                        .attach_printable("Rules didn't end the game after classical move"));
+                };
+                self.game_status.next_turn(false);
+                self.game_status.set_end(score.winner());
+                Ok(GameResult::GameEnd(score, reason))
+            }
         }
     }
 
+    /// Every move the player on turn can make in the current position.
+    ///
+    /// Yields pairs of uncollapsed fields when a mark is expected, every
+    /// field and index of the pending cycle when a collapse is expected and
+    /// the last uncollapsed field when a classical move is expected.
+    /// Yields nothing after the game has ended.
+    pub fn legal_moves(&self) -> impl Iterator<Item = Move> {
+        let move_type = (!self.game_status.is_game_end()).then(|| self.game_status.get_move_type());
+        let mark_moves = match move_type {
+            Some(MoveType::Mark) => self.mark_moves(),
+            _ => Vec::new(),
+        };
+        let collapse_moves = match move_type {
+            Some(MoveType::Collapse) => self.collapse_moves(),
+            _ => Vec::new(),
+        };
+        let classical_moves = match move_type {
+            Some(MoveType::Classical) => self
+                .board
+                .get_entangled_coordinates()
+                .into_iter()
+                .map(|field| Move::Classical { field })
+                .collect(),
+            _ => Vec::new(),
+        };
+        mark_moves
+            .into_iter()
+            .chain(collapse_moves)
+            .chain(classical_moves)
+    }
+
+    /// Every collapse choice of the pending cycle with the board it leads
+    /// to, leaving the game untouched. Empty when no collapse is expected.
+    ///
+    /// # Panics
+    ///
+    /// Will panic if a collapse of the pending cycle fails.
+    #[must_use]
+    pub fn collapse_previews(&self) -> Vec<CollapsePreview> {
+        let entangled_coordinates = self.board.get_entangled_coordinates();
+        self.legal_moves()
+            .filter_map(|player_move| match player_move {
+                Move::Collapse { field, index } => Some((player_move, field, index)),
+                _ => None,
+            })
+            .map(|(player_move, field, index)| {
+                let mut board = self.board.clone();
+                board
+                    .collapse(field, index)
+                    .expect("Collapse of the pending cycle should be valid");
+                let collapsed_fields = entangled_coordinates
+                    .iter()
+                    .filter_map(|&field_coordinate| {
+                        let (player_symbol, turn) = board.get_collapsed(field_coordinate)?;
+                        Some((field_coordinate, player_symbol, turn))
+                    })
+                    .collect();
+                CollapsePreview::new(player_move, collapsed_fields, board)
+            })
+            .collect()
+    }
+
+    /// Zobrist hash of the position, covering the spooky marks, the collapsed
+    /// fields, the pending cycle and the move expected. Kept up to date while
+    /// moves are made and undone, so it is cheap to read.
+    #[must_use]
+    pub fn position_hash(&self) -> u64 {
+        self.board.get_hash() ^ self.game_status.hash()
+    }
+
+    /// Hash shared by every position equal to this one up to a rotation or
+    /// reflection of the board, with the symmetry that takes this position
+    /// to the one the hash was taken from.
+    #[must_use]
+    pub fn canonical_hash(&self) -> (u64, Symmetry) {
+        let (hash, symmetry) = Symmetry::ALL
+            .into_iter()
+            .map(|symmetry| (self.board.hash_with(symmetry), symmetry))
+            .fold(
+                (self.board.get_hash(), Symmetry::Identity),
+                |best, other| {
+                    if other.0 < best.0 {
+                        other
+                    } else {
+                        best
+                    }
+                },
+            );
+        (hash ^ self.game_status.hash(), symmetry)
+    }
+
     #[must_use]
     pub fn get_status(&self) -> &GameStatus {
         &self.game_status
@@ -91,22 +515,105 @@ impl Game {
         &self.board
     }
 
-    /// Use this function if you want to end the game regardless of your position on the board
+    #[must_use]
+    pub fn get_rules(&self) -> &dyn Rules {
+        self.rules.as_ref()
+    }
+
+    /// Rule variant of the game, None if it's played by rules not of a
+    /// [`RuleSet`].
+    #[must_use]
+    pub fn get_rule_set(&self) -> Option<RuleSet> {
+        self.rule_set
+    }
+
+    #[must_use]
+    pub fn get_collapse_chooser(&self) -> CollapseChooser {
+        self.collapse_chooser
+    }
+
+    /// Use this function if you want to end the game regardless of your position on the board.
+    /// Moves made before can't be undone afterwards.
     ///
     /// # Errors
     ///
     /// Function will not return errors
     pub fn end_game(&mut self, winner: Option<PlayerSymbol>) -> Result<GameResult, GameError> {
         self.game_status.set_end(winner);
-        Ok(GameResult::GameEnd(winner))
+        self.interrupted = true;
+        Ok(GameResult::GameEnd(
+            Score::from_winner(winner),
+            EndReason::Interrupted,
+        ))
+    }
+
+    fn mark_moves(&self) -> Vec<Move> {
+        let coordinates = self.board.get_entangled_coordinates();
+        coordinates
+            .iter()
+            .enumerate()
+            .flat_map(|(i, &field1)| {
+                coordinates[i + 1..]
+                    .iter()
+                    .map(move |&field2| Move::Mark { field1, field2 })
+            })
+            .collect()
     }
 
-    fn check_end(&self) -> (bool, Option<PlayerSymbol>) {
-        let lines_result = self.board.check_all_lines();
-        if lines_result.is_full_line() {
-            (true, lines_result.get_winner())
-        } else {
-            (false, None)
+    fn collapse_moves(&self) -> Vec<Move> {
+        self.board
+            .get_last_cycle()
+            .map(|cycle| {
+                cycle
+                    .get_fields_coordinate()
+                    .iter()
+                    .zip(cycle.get_fields_indexes())
+                    .flat_map(|(&field, indexes)| {
+                        indexes
+                            .iter()
+                            .map(move |&index| Move::Collapse { field, index })
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Asks the rules whether the game ends with the lines on the board.
+    fn check_end(&self) -> Option<(Score, EndReason)> {
+        self.rules.end(
+            self.board.check_all_lines().get_lines(),
+            self.board.get_entangled_coordinates().len(),
+        )
+    }
+}
+
+/// Whether the marks on the board are exactly those of the turns before
+/// `turn`, each made by the player of its turn. A mark stays on both of its
+/// fields until one of them collapses to it.
+fn marks_match_turn(positions: &Array2D<Field>, turn: usize) -> bool {
+    let mut is_marked = vec![false; turn];
+    for field in positions.elements_row_major_iter() {
+        let marks = match field {
+            Field::Entangled(symbols) => symbols
+                .iter()
+                .enumerate()
+                .filter_map(|(mark_turn, player_symbol)| {
+                    player_symbol.map(|player_symbol| (mark_turn, player_symbol))
+                })
+                .collect(),
+            Field::Collapsed(player_symbol, mark_turn) => vec![(*mark_turn, *player_symbol)],
+        };
+        for (mark_turn, player_symbol) in marks {
+            if mark_turn >= turn || player_symbol != GameStatus::player_of_turn(mark_turn) {
+                return false;
+            }
+            is_marked[mark_turn] = true;
         }
     }
+    is_marked.into_iter().all(|is_marked| is_marked)
+}
+
+fn making_move_error(report: Report<BoardError>) -> Report<GameError> {
+    let kind = report.current_context().kind();
+    report.change_context(GameError::MakingMoveError(kind))
 }
@@ -1,6 +1,14 @@
 use super::*;
+use crate::board::board_error::BoardErrorKind;
 use crate::cycle::Cycle;
+use crate::field::Field;
 use crate::field_coordinate::FieldCoordinate;
+use crate::game::game_result::EndReason;
+use crate::line::LineDirection;
+use crate::rules::StandardRules;
+use crate::score::Score;
+use crate::symmetry::Symmetry;
+use crate::test_util::{collapse, mark};
 
 #[test]
 fn wrong_player_turn() {
@@ -126,7 +134,10 @@ fn player_x_win() {
     };
     let result = game.player_move(player_move, PlayerSymbol::O).unwrap();
 
-    assert_eq!(result, GameResult::GameEnd(Some(PlayerSymbol::X)));
+    assert!(matches!(
+        result,
+        GameResult::GameEnd(score, EndReason::Lines(_)) if score == Score::new(2, 0)
+    ));
 }
 
 #[test]
@@ -174,7 +185,10 @@ fn player_o_win() {
     };
     let result = game.player_move(player_move, PlayerSymbol::X).unwrap();
 
-    assert_eq!(result, GameResult::GameEnd(Some(PlayerSymbol::O)));
+    assert!(matches!(
+        result,
+        GameResult::GameEnd(score, EndReason::Lines(_)) if score == Score::new(0, 2)
+    ));
 }
 
 #[test]
@@ -228,11 +242,14 @@ fn player_x_win_diagonal_size_4() {
     };
     let result = game.player_move(player_move, PlayerSymbol::O).unwrap();
 
-    assert_eq!(result, GameResult::GameEnd(Some(PlayerSymbol::X)));
+    assert!(matches!(
+        result,
+        GameResult::GameEnd(score, EndReason::Lines(_)) if score == Score::new(2, 0)
+    ));
 }
 
 #[test]
-fn draw() {
+fn simultaneous_lines() {
     let mut game = Game::new(3);
     let player_move = Move::Mark {
         field1: FieldCoordinate { x: 0, y: 0 },
@@ -276,5 +293,898 @@ fn draw() {
     };
     let result = game.player_move(player_move, PlayerSymbol::X).unwrap();
 
-    assert_eq!(result, GameResult::GameEnd(None));
+    assert!(matches!(
+        result,
+        GameResult::GameEnd(score, EndReason::Lines(_)) if score == Score::new(2, 1)
+    ));
+    assert_eq!(Score::new(2, 1).winner(), Some(PlayerSymbol::X));
+}
+
+#[test]
+fn legal_moves_empty_board() {
+    let game = Game::new(3);
+    let legal_moves = game.legal_moves().collect::<Vec<_>>();
+    assert_eq!(legal_moves.len(), 36);
+    assert!(legal_moves
+        .iter()
+        .all(|player_move| matches!(player_move, Move::Mark { .. })));
+    assert!(legal_moves.contains(&Move::Mark {
+        field1: FieldCoordinate { x: 0, y: 0 },
+        field2: FieldCoordinate { x: 2, y: 2 },
+    }));
+}
+
+#[test]
+fn legal_moves_cycle() {
+    let mut game = Game::new(3);
+    let player_move = Move::Mark {
+        field1: FieldCoordinate { x: 0, y: 0 },
+        field2: FieldCoordinate { x: 1, y: 0 },
+    };
+    let _ = game.player_move(player_move, PlayerSymbol::X).unwrap();
+    let _ = game.player_move(player_move, PlayerSymbol::O).unwrap();
+
+    let legal_moves = game.legal_moves().collect::<Vec<_>>();
+    assert_eq!(
+        legal_moves,
+        vec![
+            Move::Collapse {
+                field: FieldCoordinate { x: 0, y: 0 },
+                index: 0,
+            },
+            Move::Collapse {
+                field: FieldCoordinate { x: 0, y: 0 },
+                index: 1,
+            },
+            Move::Collapse {
+                field: FieldCoordinate { x: 1, y: 0 },
+                index: 0,
+            },
+            Move::Collapse {
+                field: FieldCoordinate { x: 1, y: 0 },
+                index: 1,
+            },
+        ]
+    );
+}
+
+#[test]
+fn legal_moves_after_collapse() {
+    let mut game = Game::new(3);
+    let player_move = Move::Mark {
+        field1: FieldCoordinate { x: 0, y: 0 },
+        field2: FieldCoordinate { x: 1, y: 0 },
+    };
+    let _ = game.player_move(player_move, PlayerSymbol::X).unwrap();
+    let _ = game.player_move(player_move, PlayerSymbol::O).unwrap();
+    let player_move = Move::Collapse {
+        field: FieldCoordinate { x: 0, y: 0 },
+        index: 0,
+    };
+    let _ = game.player_move(player_move, PlayerSymbol::X).unwrap();
+
+    let legal_moves = game.legal_moves().collect::<Vec<_>>();
+    assert_eq!(legal_moves.len(), 21);
+    for player_move in legal_moves {
+        let Move::Mark { field1, field2 } = player_move else {
+            panic!("Only marks should be legal");
+        };
+        assert!(field1.y > 0 || field1.x > 1);
+        assert!(field2.y > 0 || field2.x > 1);
+    }
+
+    let player_move = Move::Mark {
+        field1: FieldCoordinate { x: 2, y: 0 },
+        field2: FieldCoordinate { x: 0, y: 1 },
+    };
+    let result = game.player_move(player_move, PlayerSymbol::X).unwrap();
+    assert_eq!(result, GameResult::NextTurn);
+}
+
+#[test]
+fn legal_moves_are_accepted() {
+    let mut game = Game::new(3);
+    let player_move = Move::Mark {
+        field1: FieldCoordinate { x: 0, y: 0 },
+        field2: FieldCoordinate { x: 1, y: 0 },
+    };
+    let _ = game.player_move(player_move, PlayerSymbol::X).unwrap();
+
+    for player_move in game.legal_moves().collect::<Vec<_>>() {
+        let mut game = Game::new(3);
+        let first_move = Move::Mark {
+            field1: FieldCoordinate { x: 0, y: 0 },
+            field2: FieldCoordinate { x: 1, y: 0 },
+        };
+        let _ = game.player_move(first_move, PlayerSymbol::X).unwrap();
+        assert!(game.player_move(player_move, PlayerSymbol::O).is_ok());
+    }
+}
+
+#[test]
+fn legal_moves_game_end() {
+    let mut game = Game::new(3);
+    let _ = game.end_game(Some(PlayerSymbol::X)).unwrap();
+    assert_eq!(game.legal_moves().count(), 0);
+}
+
+#[test]
+fn undo_without_move() {
+    let mut game = Game::new(3);
+    let result = game.undo().unwrap_err();
+    assert_eq!(result.current_context(), &GameError::NothingToUndo);
+    let result = game.redo().unwrap_err();
+    assert_eq!(result.current_context(), &GameError::NothingToRedo);
+}
+
+#[test]
+fn undo_mark() {
+    let mut game = Game::new(3);
+    let player_move = Move::Mark {
+        field1: FieldCoordinate { x: 0, y: 0 },
+        field2: FieldCoordinate { x: 1, y: 0 },
+    };
+    let _ = game.player_move(player_move, PlayerSymbol::X).unwrap();
+    let (undone_move, player_symbol) = game.undo().unwrap();
+
+    assert_eq!(undone_move, player_move);
+    assert_eq!(player_symbol, PlayerSymbol::X);
+    assert_eq!(game.board.get_positions(), Board::new(3).get_positions());
+    assert_eq!(game.game_status, GameStatus::new());
+    assert_eq!(game.legal_moves().count(), 36);
+}
+
+#[test]
+fn undo_collapse_restores_cycle() {
+    let mut game = Game::new(3);
+    let player_move = Move::Mark {
+        field1: FieldCoordinate { x: 0, y: 0 },
+        field2: FieldCoordinate { x: 1, y: 0 },
+    };
+    let _ = game.player_move(player_move, PlayerSymbol::X).unwrap();
+    let _ = game.player_move(player_move, PlayerSymbol::O).unwrap();
+    let positions = game.board.get_positions();
+    let game_status = game.game_status.clone();
+    let cycle = game.board.get_last_cycle().cloned();
+
+    let collapse_move = Move::Collapse {
+        field: FieldCoordinate { x: 0, y: 0 },
+        index: 0,
+    };
+    let _ = game.player_move(collapse_move, PlayerSymbol::X).unwrap();
+    let _ = game.undo().unwrap();
+
+    assert_eq!(game.board.get_positions(), positions);
+    assert_eq!(game.game_status, game_status);
+    assert_eq!(game.board.get_last_cycle().cloned(), cycle);
+
+    let collapse_move = Move::Collapse {
+        field: FieldCoordinate { x: 1, y: 0 },
+        index: 0,
+    };
+    let result = game.player_move(collapse_move, PlayerSymbol::X).unwrap();
+    assert_eq!(result, GameResult::TurnAfterCollapse);
+}
+
+#[test]
+fn undo_restores_connections() {
+    let mut game = Game::new(3);
+    let player_move = Move::Mark {
+        field1: FieldCoordinate { x: 0, y: 0 },
+        field2: FieldCoordinate { x: 1, y: 0 },
+    };
+    let _ = game.player_move(player_move, PlayerSymbol::X).unwrap();
+    let _ = game.player_move(player_move, PlayerSymbol::O).unwrap();
+    let _ = game.undo().unwrap();
+
+    let player_move = Move::Mark {
+        field1: FieldCoordinate { x: 1, y: 0 },
+        field2: FieldCoordinate { x: 2, y: 0 },
+    };
+    let result = game.player_move(player_move, PlayerSymbol::O).unwrap();
+    assert_eq!(result, GameResult::NextTurn);
+}
+
+#[test]
+fn redo_moves() {
+    let mut game = Game::new(3);
+    let player_move = Move::Mark {
+        field1: FieldCoordinate { x: 0, y: 0 },
+        field2: FieldCoordinate { x: 1, y: 0 },
+    };
+    let _ = game.player_move(player_move, PlayerSymbol::X).unwrap();
+    let _ = game.player_move(player_move, PlayerSymbol::O).unwrap();
+    let positions = game.board.get_positions();
+    let _ = game.undo().unwrap();
+    let _ = game.undo().unwrap();
+
+    assert_eq!(game.redo().unwrap(), GameResult::NextTurn);
+    let cycle = Cycle::new(
+        vec![
+            FieldCoordinate { x: 0, y: 0 },
+            FieldCoordinate { x: 1, y: 0 },
+        ],
+        vec![vec![0, 1], vec![0, 1]],
+    );
+    assert_eq!(game.redo().unwrap(), GameResult::NextTurnCycle(cycle));
+    assert_eq!(game.board.get_positions(), positions);
+    assert_eq!(game.game_status.get_turn(), 2);
+}
+
+#[test]
+fn new_move_clears_redo() {
+    let mut game = Game::new(3);
+    let player_move = Move::Mark {
+        field1: FieldCoordinate { x: 0, y: 0 },
+        field2: FieldCoordinate { x: 1, y: 0 },
+    };
+    let _ = game.player_move(player_move, PlayerSymbol::X).unwrap();
+    let _ = game.undo().unwrap();
+    let player_move = Move::Mark {
+        field1: FieldCoordinate { x: 1, y: 1 },
+        field2: FieldCoordinate { x: 2, y: 2 },
+    };
+    let _ = game.player_move(player_move, PlayerSymbol::X).unwrap();
+
+    let result = game.redo().unwrap_err();
+    assert_eq!(result.current_context(), &GameError::NothingToRedo);
+}
+
+#[test]
+fn snapshot_round_trip() {
+    let mut game = Game::new(3);
+    let player_move = Move::Mark {
+        field1: FieldCoordinate { x: 0, y: 0 },
+        field2: FieldCoordinate { x: 1, y: 0 },
+    };
+    let _ = game.player_move(player_move, PlayerSymbol::X).unwrap();
+    let player_move = Move::Mark {
+        field1: FieldCoordinate { x: 1, y: 0 },
+        field2: FieldCoordinate { x: 2, y: 2 },
+    };
+    let _ = game.player_move(player_move, PlayerSymbol::O).unwrap();
+    let player_move = Move::Mark {
+        field1: FieldCoordinate { x: 2, y: 2 },
+        field2: FieldCoordinate { x: 0, y: 0 },
+    };
+    let _ = game.player_move(player_move, PlayerSymbol::X).unwrap();
+
+    let json = serde_json::to_string(&game.snapshot()).unwrap();
+    let mut restored = Game::from_snapshot(serde_json::from_str(&json).unwrap()).unwrap();
+
+    assert_eq!(restored.snapshot(), game.snapshot());
+    assert_eq!(
+        restored.legal_moves().collect::<Vec<_>>(),
+        game.legal_moves().collect::<Vec<_>>()
+    );
+    let player_move = Move::Collapse {
+        field: FieldCoordinate { x: 1, y: 0 },
+        index: 1,
+    };
+    assert_eq!(
+        restored.player_move(player_move, PlayerSymbol::O).unwrap(),
+        game.player_move(player_move, PlayerSymbol::O).unwrap()
+    );
+    assert_eq!(restored.snapshot(), game.snapshot());
+}
+
+#[test]
+fn snapshot_keeps_connections() {
+    let mut game = Game::new(3);
+    let player_move = Move::Mark {
+        field1: FieldCoordinate { x: 0, y: 0 },
+        field2: FieldCoordinate { x: 1, y: 0 },
+    };
+    let _ = game.player_move(player_move, PlayerSymbol::X).unwrap();
+    let mut restored = Game::from_snapshot(game.snapshot()).unwrap();
+
+    let result = restored.player_move(player_move, PlayerSymbol::O).unwrap();
+    assert!(matches!(result, GameResult::NextTurnCycle(_)));
+}
+
+#[test]
+fn invalid_snapshot() {
+    let game = Game::new(3);
+    let mut snapshot = game.snapshot();
+    snapshot.size = 4;
+    let result = Game::from_snapshot(snapshot).err().unwrap();
+    assert_eq!(result.current_context(), &GameError::InvalidSnapshot);
+
+    let mut snapshot = game.snapshot();
+    snapshot.connections.push((
+        FieldCoordinate { x: 0, y: 0 },
+        FieldCoordinate { x: 1, y: 0 },
+        0,
+    ));
+    let result = Game::from_snapshot(snapshot).err().unwrap();
+    assert_eq!(result.current_context(), &GameError::InvalidSnapshot);
+}
+
+#[test]
+fn invalid_size() {
+    for size in [0, 1] {
+        let result = Game::with_win_length(size, 1).err().unwrap();
+        assert_eq!(result.current_context(), &GameError::InvalidSize);
+    }
+    assert_eq!(
+        Game::with_win_length(2, 1).unwrap().legal_moves().count(),
+        6
+    );
+
+    let mut snapshot = Game::new(3).snapshot();
+    snapshot.size = 1;
+    snapshot.win_length = 1;
+    snapshot.positions = Array2D::filled_with(Field::Entangled(vec![None]), 1, 1);
+    let result = Game::from_snapshot(snapshot).err().unwrap();
+    assert_eq!(result.current_context(), &GameError::InvalidSnapshot);
+}
+
+#[test]
+fn invalid_win_length() {
+    for win_length in [0, 4] {
+        let result = Game::with_win_length(3, win_length).err().unwrap();
+        assert_eq!(result.current_context(), &GameError::InvalidWinLength);
+    }
+    assert!(Game::with_win_length(3, 1).is_ok());
+
+    let mut snapshot = Game::new(3).snapshot();
+    snapshot.win_length = 0;
+    let result = Game::from_snapshot(snapshot).err().unwrap();
+    assert_eq!(result.current_context(), &GameError::InvalidSnapshot);
+}
+
+#[test]
+fn invalid_snapshot_cycle() {
+    let mut game = Game::new(3);
+    let _ = game
+        .player_move(mark((0, 0), (1, 0)), PlayerSymbol::X)
+        .unwrap();
+    let _ = game
+        .player_move(mark((0, 0), (1, 0)), PlayerSymbol::O)
+        .unwrap();
+    let snapshot = game.snapshot();
+    assert!(Game::from_snapshot(snapshot.clone()).is_ok());
+
+    let field = |x, y| FieldCoordinate { x, y };
+    let cycles = [
+        // Mark of a turn not on the board
+        Cycle::new(vec![field(0, 0), field(1, 0)], vec![vec![0, 5], vec![0, 5]]),
+        // Field without the indexes of its neighbours
+        Cycle::new(vec![field(0, 0), field(1, 0)], vec![vec![0], vec![0, 1]]),
+        // Field not marked by the cycle
+        Cycle::new(vec![field(0, 0), field(2, 2)], vec![vec![0, 1], vec![0, 1]]),
+        Cycle::new(vec![field(0, 0), field(0, 0)], vec![vec![0, 1], vec![0, 1]]),
+        Cycle::new(vec![field(0, 0), field(1, 0)], vec![vec![0, 1]]),
+    ];
+    for cycle in cycles {
+        let mut snapshot = snapshot.clone();
+        snapshot.last_cycle = Some(cycle);
+        let result = Game::from_snapshot(snapshot).err().unwrap();
+        assert_eq!(result.current_context(), &GameError::InvalidSnapshot);
+    }
+
+    let mut snapshot = snapshot;
+    snapshot.last_cycle = None;
+    let result = Game::from_snapshot(snapshot).err().unwrap();
+    assert_eq!(result.current_context(), &GameError::InvalidSnapshot);
+}
+
+#[test]
+fn invalid_snapshot_status() {
+    let mut game = Game::new(3);
+    let _ = game
+        .player_move(mark((0, 0), (1, 0)), PlayerSymbol::X)
+        .unwrap();
+    let snapshot = serde_json::to_value(game.snapshot()).unwrap();
+    assert!(Game::from_snapshot(serde_json::from_value(snapshot.clone()).unwrap()).is_ok());
+
+    let statuses = [
+        // Turn past the end of the board
+        serde_json::json!({"turn": 100, "player_turn": "X"}),
+        // Turn without its mark on the board
+        serde_json::json!({"turn": 3}),
+        // Turn already marked
+        serde_json::json!({"turn": 0, "player_turn": "X"}),
+        serde_json::json!({"player_turn": "X"}),
+        // Collapse chosen without a pending cycle
+        serde_json::json!({"collapse_player": "X"}),
+        // Unfinished game expecting a classical move with fields left
+        serde_json::json!({"move_type": "Classical"}),
+    ];
+    for status in statuses {
+        let mut snapshot = snapshot.clone();
+        for (key, value) in status.as_object().unwrap() {
+            snapshot["game_status"][key] = value.clone();
+        }
+        let result = Game::from_snapshot(serde_json::from_value(snapshot).unwrap())
+            .err()
+            .unwrap();
+        assert_eq!(result.current_context(), &GameError::InvalidSnapshot);
+    }
+}
+
+#[test]
+fn snapshot_keeps_rules() {
+    let mut game = Game::new(3)
+        .with_rule_set(RuleSet::Misere)
+        .with_collapse_chooser(CollapseChooser::Creator);
+    let _ = game
+        .player_move(mark((0, 0), (1, 0)), PlayerSymbol::X)
+        .unwrap();
+    let json = serde_json::to_string(&game.snapshot()).unwrap();
+    let mut restored = Game::from_snapshot(serde_json::from_str(&json).unwrap()).unwrap();
+    assert_eq!(restored.get_rule_set(), Some(RuleSet::Misere));
+    assert_eq!(restored.get_collapse_chooser(), CollapseChooser::Creator);
+
+    let _ = restored
+        .player_move(mark((0, 0), (1, 0)), PlayerSymbol::O)
+        .unwrap();
+    assert_eq!(restored.get_status().get_player_turn(), PlayerSymbol::O);
+}
+
+#[test]
+fn snapshot_with_custom_rules() {
+    let game = Game::with_rules(3, 3, Arc::new(StandardRules)).unwrap();
+    assert_eq!(game.get_rule_set(), None);
+    let result = Game::from_snapshot(game.snapshot()).err().unwrap();
+    assert_eq!(result.current_context(), &GameError::InvalidSnapshot);
+
+    let restored =
+        Game::from_snapshot_with_rules(game.snapshot(), Arc::new(StandardRules)).unwrap();
+    assert_eq!(restored.snapshot(), game.snapshot());
+}
+
+#[test]
+fn draw_board_full() {
+    let mut game = Game::new(3);
+    let moves = [
+        (mark((0, 0), (1, 0)), PlayerSymbol::X),
+        (mark((0, 0), (1, 0)), PlayerSymbol::O),
+        (collapse((0, 0), 0), PlayerSymbol::X),
+        (mark((2, 0), (1, 1)), PlayerSymbol::X),
+        (mark((2, 0), (1, 1)), PlayerSymbol::O),
+        (collapse((2, 0), 2), PlayerSymbol::X),
+        (mark((0, 1), (2, 1)), PlayerSymbol::X),
+        (mark((0, 1), (2, 1)), PlayerSymbol::O),
+        (collapse((0, 1), 4), PlayerSymbol::X),
+        (mark((1, 2), (0, 2)), PlayerSymbol::X),
+        (mark((0, 2), (2, 2)), PlayerSymbol::O),
+    ];
+    for (player_move, player_symbol) in moves {
+        let result = game.player_move(player_move, player_symbol).unwrap();
+        assert!(!matches!(result, GameResult::GameEnd(..)));
+    }
+    let result = game
+        .player_move(mark((2, 2), (1, 2)), PlayerSymbol::X)
+        .unwrap();
+    assert!(matches!(result, GameResult::NextTurnCycle(_)));
+
+    let result = game
+        .player_move(collapse((1, 2), 6), PlayerSymbol::O)
+        .unwrap();
+    assert_eq!(
+        result,
+        GameResult::GameEnd(Score::default(), EndReason::BoardFull)
+    );
+    assert!(game.get_status().is_game_end());
+    assert_eq!(game.legal_moves().count(), 0);
+}
+
+#[test]
+fn classical_move_last_field() {
+    let mut game = Game::new(3);
+    let moves = [
+        (mark((0, 0), (1, 0)), PlayerSymbol::X),
+        (mark((0, 0), (1, 0)), PlayerSymbol::O),
+        (collapse((0, 0), 0), PlayerSymbol::X),
+        (mark((2, 0), (1, 1)), PlayerSymbol::X),
+        (mark((2, 0), (1, 1)), PlayerSymbol::O),
+        (collapse((2, 0), 2), PlayerSymbol::X),
+        (mark((0, 1), (2, 1)), PlayerSymbol::X),
+        (mark((0, 1), (2, 1)), PlayerSymbol::O),
+        (collapse((0, 1), 4), PlayerSymbol::X),
+        (mark((1, 2), (0, 2)), PlayerSymbol::X),
+        (mark((1, 2), (0, 2)), PlayerSymbol::O),
+    ];
+    for (player_move, player_symbol) in moves {
+        let _ = game.player_move(player_move, player_symbol).unwrap();
+    }
+    let result = game
+        .player_move(collapse((1, 2), 6), PlayerSymbol::X)
+        .unwrap();
+    assert_eq!(result, GameResult::TurnAfterCollapse);
+    let classical_move = Move::Classical {
+        field: FieldCoordinate { x: 2, y: 2 },
+    };
+    assert_eq!(game.legal_moves().collect::<Vec<_>>(), vec![classical_move]);
+
+    let result = game
+        .player_move(mark((2, 2), (1, 2)), PlayerSymbol::X)
+        .unwrap_err();
+    assert_eq!(result.current_context(), &GameError::MoveTypeError);
+
+    let result = game.player_move(classical_move, PlayerSymbol::X).unwrap();
+    assert_eq!(
+        result,
+        GameResult::GameEnd(Score::default(), EndReason::BoardFull)
+    );
+    assert_eq!(
+        game.board.get_positions()[(2, 2)],
+        Field::Collapsed(PlayerSymbol::X, 8)
+    );
+}
+
+#[test]
+fn classical_move_completes_line() {
+    let mut game = Game::new(3);
+    let moves = [
+        (mark((0, 0), (1, 0)), PlayerSymbol::X),
+        (mark((0, 0), (1, 0)), PlayerSymbol::O),
+        (collapse((0, 0), 0), PlayerSymbol::X),
+        (mark((2, 0), (1, 1)), PlayerSymbol::X),
+        (mark((2, 0), (1, 1)), PlayerSymbol::O),
+        (collapse((2, 0), 2), PlayerSymbol::X),
+        (mark((0, 1), (2, 1)), PlayerSymbol::X),
+        (mark((0, 1), (2, 1)), PlayerSymbol::O),
+        (collapse((0, 1), 5), PlayerSymbol::X),
+        (mark((1, 2), (0, 2)), PlayerSymbol::X),
+        (mark((1, 2), (0, 2)), PlayerSymbol::O),
+        (collapse((1, 2), 6), PlayerSymbol::X),
+    ];
+    for (player_move, player_symbol) in moves {
+        let _ = game.player_move(player_move, player_symbol).unwrap();
+    }
+    let classical_move = Move::Classical {
+        field: FieldCoordinate { x: 2, y: 2 },
+    };
+    let result = game.player_move(classical_move, PlayerSymbol::X).unwrap();
+    assert!(matches!(
+        result,
+        GameResult::GameEnd(score, EndReason::Lines(_)) if score == Score::new(2, 0)
+    ));
+}
+
+#[test]
+fn classical_move_not_allowed() {
+    let mut game = Game::new(3);
+    let classical_move = Move::Classical {
+        field: FieldCoordinate { x: 0, y: 0 },
+    };
+    let result = game
+        .player_move(classical_move, PlayerSymbol::X)
+        .unwrap_err();
+    assert_eq!(result.current_context(), &GameError::MoveTypeError);
+}
+
+#[test]
+fn end_game_interrupted() {
+    let mut game = Game::new(3);
+    let result = game.end_game(Some(PlayerSymbol::O)).unwrap();
+    assert_eq!(
+        result,
+        GameResult::GameEnd(Score::new(0, 2), EndReason::Interrupted)
+    );
+}
+
+#[test]
+fn undo_after_end_game() {
+    let mut game = Game::new(3);
+    let _ = game
+        .player_move(mark((0, 0), (1, 0)), PlayerSymbol::X)
+        .unwrap();
+    let _ = game.end_game(Some(PlayerSymbol::O)).unwrap();
+
+    let result = game.undo().unwrap_err();
+    assert_eq!(result.current_context(), &GameError::MoveAfterEnd);
+    assert!(game.get_status().is_game_end());
+    assert_eq!(game.get_board().get_connections().len(), 1);
+}
+
+#[test]
+fn win_length_shorter_than_size() {
+    let moves = [
+        (mark((0, 0), (1, 0)), PlayerSymbol::X),
+        (mark((0, 3), (1, 3)), PlayerSymbol::O),
+        (mark((1, 0), (2, 0)), PlayerSymbol::X),
+        (mark((1, 3), (2, 3)), PlayerSymbol::O),
+        (mark((2, 0), (0, 0)), PlayerSymbol::X),
+    ];
+    let mut game = Game::with_win_length(4, 3).unwrap();
+    let mut full_length_game = Game::new(4);
+    for (player_move, player_symbol) in moves {
+        let _ = game.player_move(player_move, player_symbol).unwrap();
+        let _ = full_length_game
+            .player_move(player_move, player_symbol)
+            .unwrap();
+    }
+
+    let result = game
+        .player_move(collapse((0, 0), 0), PlayerSymbol::O)
+        .unwrap();
+    assert!(matches!(
+        result,
+        GameResult::GameEnd(score, EndReason::Lines(_)) if score == Score::new(2, 0)
+    ));
+    let result = full_length_game
+        .player_move(collapse((0, 0), 0), PlayerSymbol::O)
+        .unwrap();
+    assert_eq!(result, GameResult::TurnAfterCollapse);
+}
+
+#[test]
+fn collapse_previews() {
+    let mut game = Game::new(3);
+    game.player_move(mark((0, 0), (1, 0)), PlayerSymbol::X)
+        .unwrap();
+    game.player_move(mark((1, 0), (2, 0)), PlayerSymbol::O)
+        .unwrap();
+    game.player_move(mark((2, 0), (2, 1)), PlayerSymbol::X)
+        .unwrap();
+    assert!(game.collapse_previews().is_empty());
+    game.player_move(mark((0, 0), (2, 0)), PlayerSymbol::O)
+        .unwrap();
+
+    let previews = game.collapse_previews();
+    assert_eq!(previews.len(), 6);
+    assert!(previews
+        .iter()
+        .all(|preview| preview.get_collapsed_fields().len() == 4));
+    assert_eq!(game.get_board().get_entangled_coordinates().len(), 9);
+
+    let preview = previews
+        .iter()
+        .find(|preview| preview.get_player_move() == collapse((0, 0), 0))
+        .unwrap();
+    let collapsed_fields = preview.get_collapsed_fields();
+    assert!(collapsed_fields.contains(&(FieldCoordinate { x: 0, y: 0 }, PlayerSymbol::X, 0)));
+    assert!(collapsed_fields.contains(&(FieldCoordinate { x: 2, y: 1 }, PlayerSymbol::X, 2)));
+
+    game.player_move(collapse((0, 0), 0), PlayerSymbol::X)
+        .unwrap();
+    assert_eq!(
+        game.get_board().get_positions(),
+        preview.get_board().get_positions()
+    );
+}
+
+#[test]
+fn pending_cycle_is_readable() {
+    let mut game = Game::new(3);
+    game.player_move(mark((0, 0), (1, 0)), PlayerSymbol::X)
+        .unwrap();
+    game.player_move(mark((0, 0), (1, 0)), PlayerSymbol::O)
+        .unwrap();
+    let cycle = game.get_board().get_last_cycle().unwrap();
+    assert_eq!(cycle.len(), 2);
+    assert_eq!(
+        cycle.get_fields_coordinate(),
+        &[
+            FieldCoordinate { x: 0, y: 0 },
+            FieldCoordinate { x: 1, y: 0 }
+        ]
+    );
+    assert_eq!(cycle.get_fields_indexes(), &[vec![0, 1], vec![0, 1]]);
+}
+
+#[test]
+fn game_end_lists_winning_line() {
+    let mut game = Game::new(3);
+    for (turn, column) in [(0, 0), (2, 1)] {
+        game.player_move(mark((column, 0), (column, 1)), PlayerSymbol::X)
+            .unwrap();
+        game.player_move(mark((column, 0), (column, 1)), PlayerSymbol::O)
+            .unwrap();
+        game.player_move(collapse((column, 0), turn), PlayerSymbol::X)
+            .unwrap();
+    }
+    game.player_move(mark((2, 0), (2, 2)), PlayerSymbol::X)
+        .unwrap();
+    game.player_move(mark((2, 0), (2, 2)), PlayerSymbol::O)
+        .unwrap();
+    let result = game
+        .player_move(collapse((2, 0), 4), PlayerSymbol::X)
+        .unwrap();
+
+    let GameResult::GameEnd(score, EndReason::Lines(lines)) = result else {
+        panic!("Game should end with a line");
+    };
+    assert_eq!(score, Score::new(2, 0));
+    assert_eq!(lines.len(), 1);
+    assert_eq!(lines[0].get_direction(), LineDirection::Row(0));
+    assert_eq!(lines[0].get_player_symbol(), PlayerSymbol::X);
+    assert_eq!(lines[0].get_max_turn(), 4);
+    assert_eq!(
+        lines[0].get_fields(),
+        &[
+            FieldCoordinate { x: 0, y: 0 },
+            FieldCoordinate { x: 1, y: 0 },
+            FieldCoordinate { x: 2, y: 0 },
+        ]
+    );
+}
+
+#[test]
+fn making_move_error_kinds() {
+    let mut game = Game::new(3);
+    let error_kind = |game: &mut Game, player_move, player_symbol| {
+        *game
+            .player_move(player_move, player_symbol)
+            .err()
+            .unwrap()
+            .current_context()
+    };
+    assert_eq!(
+        error_kind(&mut game, mark((0, 0), (0, 0)), PlayerSymbol::X),
+        GameError::MakingMoveError(BoardErrorKind::SameCoordinates)
+    );
+    assert_eq!(
+        error_kind(&mut game, mark((0, 0), (3, 0)), PlayerSymbol::X),
+        GameError::MakingMoveError(BoardErrorKind::OutOfBoard)
+    );
+    game.player_move(mark((0, 0), (1, 0)), PlayerSymbol::X)
+        .unwrap();
+    game.player_move(mark((0, 0), (1, 0)), PlayerSymbol::O)
+        .unwrap();
+    assert_eq!(
+        error_kind(&mut game, collapse((2, 2), 0), PlayerSymbol::X),
+        GameError::MakingMoveError(BoardErrorKind::FieldNotInCycle)
+    );
+    assert_eq!(
+        error_kind(&mut game, collapse((0, 0), 2), PlayerSymbol::X),
+        GameError::MakingMoveError(BoardErrorKind::IndexNotInField)
+    );
+    game.player_move(collapse((0, 0), 0), PlayerSymbol::X)
+        .unwrap();
+    assert_eq!(
+        error_kind(&mut game, mark((0, 0), (2, 2)), PlayerSymbol::X),
+        GameError::MakingMoveError(BoardErrorKind::FieldCollapsed)
+    );
+}
+
+#[test]
+fn validate_move_keeps_game() {
+    let mut game = Game::new(3);
+    let validate_kind = |game: &Game, player_move: Move, player_symbol| {
+        *game
+            .validate_move(&player_move, player_symbol)
+            .unwrap_err()
+            .current_context()
+    };
+    assert_eq!(
+        validate_kind(&game, mark((0, 0), (1, 0)), PlayerSymbol::O),
+        GameError::PlayerTurnError
+    );
+    assert_eq!(
+        validate_kind(&game, collapse((0, 0), 0), PlayerSymbol::X),
+        GameError::MoveTypeError
+    );
+    assert_eq!(
+        validate_kind(&game, mark((1, 1), (1, 1)), PlayerSymbol::X),
+        GameError::MakingMoveError(BoardErrorKind::SameCoordinates)
+    );
+    assert!(game
+        .validate_move(&mark((0, 0), (1, 0)), PlayerSymbol::X)
+        .is_ok());
+
+    game.player_move(mark((0, 0), (1, 0)), PlayerSymbol::X)
+        .unwrap();
+    game.player_move(mark((0, 0), (1, 0)), PlayerSymbol::O)
+        .unwrap();
+    let snapshot = game.snapshot();
+    assert_eq!(
+        validate_kind(&game, collapse((0, 0), 3), PlayerSymbol::X),
+        GameError::MakingMoveError(BoardErrorKind::IndexNotInField)
+    );
+    assert_eq!(
+        validate_kind(&game, collapse((2, 2), 0), PlayerSymbol::X),
+        GameError::MakingMoveError(BoardErrorKind::FieldNotInCycle)
+    );
+    for player_move in game.legal_moves() {
+        assert!(game.validate_move(&player_move, PlayerSymbol::X).is_ok());
+    }
+    assert_eq!(game.snapshot(), snapshot);
+}
+
+#[test]
+fn undo_restores_identical_state() {
+    for seed in 0..20 {
+        let mut game = Game::new(3);
+        let mut snapshots = vec![game.snapshot()];
+        while let Some(player_move) = {
+            let moves = game.legal_moves().collect::<Vec<_>>();
+            (!moves.is_empty()).then(|| moves[(seed * 7 + snapshots.len() * 5) % moves.len()])
+        } {
+            let player_symbol = game.get_status().get_player_turn();
+            game.player_move(player_move, player_symbol).unwrap();
+            snapshots.push(game.snapshot());
+        }
+        let played = game.clone();
+        snapshots.pop();
+        while let Some(snapshot) = snapshots.pop() {
+            game.undo().unwrap();
+            assert_eq!(game.snapshot(), snapshot);
+        }
+        while game.redo().is_ok() {}
+        assert_eq!(game.snapshot(), played.snapshot());
+    }
+}
+
+/// Plays the moves picked by the seed until the game ends.
+fn play_seeded(seed: usize, size: usize) -> Vec<(Move, PlayerSymbol)> {
+    let mut game = Game::new(size);
+    let mut moves = Vec::new();
+    loop {
+        let legal_moves = game.legal_moves().collect::<Vec<_>>();
+        if legal_moves.is_empty() {
+            return moves;
+        }
+        let player_move = legal_moves[(seed * 7 + moves.len() * 5) % legal_moves.len()];
+        let player_symbol = game.get_status().get_player_turn();
+        game.player_move(player_move, player_symbol).unwrap();
+        moves.push((player_move, player_symbol));
+    }
+}
+
+#[test]
+fn position_hash_follows_moves() {
+    for seed in 0..10 {
+        let mut game = Game::new(3);
+        let mut hashes = vec![game.position_hash()];
+        for (player_move, player_symbol) in play_seeded(seed, 3) {
+            game.player_move(player_move, player_symbol).unwrap();
+            let hash = game.position_hash();
+            assert_eq!(
+                hash,
+                game.board.hash_with(Symmetry::Identity) ^ game.game_status.hash()
+            );
+            let restored = Game::from_snapshot(game.snapshot()).unwrap();
+            assert_eq!(restored.position_hash(), hash);
+            assert!(!hashes.contains(&hash));
+            hashes.push(hash);
+        }
+        hashes.pop();
+        while let Some(hash) = hashes.pop() {
+            game.undo().unwrap();
+            assert_eq!(game.position_hash(), hash);
+        }
+    }
+}
+
+#[test]
+fn canonical_hash_of_symmetric_games() {
+    for seed in 0..10 {
+        let mut games = Symmetry::ALL.map(|symmetry| (Game::new(4), symmetry, Vec::new()));
+        for (player_move, player_symbol) in play_seeded(seed, 4) {
+            for (game, symmetry, moves) in &mut games {
+                let player_move = symmetry.apply_move(player_move, 4);
+                game.player_move(player_move, player_symbol).unwrap();
+                moves.push((player_move, player_symbol));
+            }
+            let (canonical_hash, _) = games[0].0.canonical_hash();
+            for (game, _, moves) in &games {
+                let (hash, symmetry) = game.canonical_hash();
+                assert_eq!(hash, canonical_hash);
+                let mut canonical_game = Game::new(4);
+                for &(player_move, player_symbol) in moves {
+                    canonical_game
+                        .player_move(symmetry.apply_move(player_move, 4), player_symbol)
+                        .unwrap();
+                }
+                assert_eq!(canonical_game.position_hash(), canonical_hash);
+            }
+        }
+    }
+}
+
+#[test]
+fn symmetry_inverse() {
+    let field = FieldCoordinate { x: 1, y: 0 };
+    for symmetry in Symmetry::ALL {
+        assert_eq!(symmetry.inverse().apply(symmetry.apply(field, 4), 4), field);
+    }
+    let moved = Symmetry::ALL.map(|symmetry| symmetry.apply(field, 4));
+    assert!(moved
+        .iter()
+        .enumerate()
+        .all(|(i, field)| !moved[i + 1..].contains(field)));
 }
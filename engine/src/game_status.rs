@@ -1,15 +1,20 @@
 use crate::move_type::MoveType;
 use crate::player_move::Move;
 use crate::player_symbol::PlayerSymbol;
-use serde::Serialize;
+use crate::zobrist;
+use serde::{Deserialize, Serialize};
 
-#[derive(Serialize)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
 pub struct GameStatus {
     turn: usize,
     player_turn: PlayerSymbol,
     move_type: MoveType,
     is_end: bool,
     winner: Option<PlayerSymbol>,
+    /// Player choosing the collapse of the pending cycle, if it isn't the
+    /// player on turn.
+    #[serde(default)]
+    collapse_player: Option<PlayerSymbol>,
 }
 
 impl Default for GameStatus {
@@ -26,40 +31,94 @@ impl GameStatus {
             move_type: MoveType::Mark,
             is_end: false,
             winner: None,
+            collapse_player: None,
         }
     }
 
     pub fn next_turn(&mut self, is_collapsed: bool) {
         if self.move_type == MoveType::Collapse {
             self.move_type = MoveType::Mark;
+            self.collapse_player = None;
         } else {
             self.turn += 1;
             self.player_turn = PlayerSymbol::opposite_symbol(self.player_turn);
-            if is_collapsed {
-                self.move_type = MoveType::Collapse;
-            }
+            self.move_type = if is_collapsed {
+                MoveType::Collapse
+            } else {
+                MoveType::Mark
+            };
         }
     }
 
+    /// Expect a classical move instead of a mark, used when only one field
+    /// is left uncollapsed.
+    pub fn set_classical(&mut self) {
+        self.move_type = MoveType::Classical;
+    }
+
     pub fn is_game_end(&self) -> bool {
         self.is_end
     }
 
     pub fn is_player_turn(&self, player_symbol: PlayerSymbol) -> bool {
-        self.player_turn == player_symbol
+        self.get_player_turn() == player_symbol
+    }
+
+    /// Lets another player than the one on turn choose the collapse of the
+    /// pending cycle.
+    pub(crate) fn set_collapse_player(&mut self, player_symbol: PlayerSymbol) {
+        self.collapse_player = (player_symbol != self.player_turn).then_some(player_symbol);
     }
 
     pub fn is_good_move_type(&self, player_move: &Move) -> bool {
         match player_move {
             Move::Collapse { .. } => self.move_type == MoveType::Collapse,
             Move::Mark { .. } => self.move_type == MoveType::Mark,
+            Move::Classical { .. } => self.move_type == MoveType::Classical,
         }
     }
 
+    /// Player expected to make the next move.
+    pub fn get_player_turn(&self) -> PlayerSymbol {
+        self.collapse_player.unwrap_or(self.player_turn)
+    }
+
     pub fn get_turn(&self) -> usize {
         self.turn
     }
 
+    /// Player making the marks of the turn, X the even and O the odd ones.
+    pub(crate) fn player_of_turn(turn: usize) -> PlayerSymbol {
+        if turn.is_multiple_of(2) {
+            PlayerSymbol::X
+        } else {
+            PlayerSymbol::O
+        }
+    }
+
+    /// Whether the player on turn follows from the turn and another player
+    /// only chooses the collapse of a pending cycle.
+    pub(crate) fn is_consistent(&self) -> bool {
+        self.player_turn == GameStatus::player_of_turn(self.turn)
+            && self.collapse_player.is_none_or(|collapse_player| {
+                collapse_player != self.player_turn && self.move_type == MoveType::Collapse
+            })
+    }
+
+    pub(crate) fn get_move_type(&self) -> MoveType {
+        self.move_type
+    }
+
+    /// Zobrist key of the turn, the player and the move expected.
+    pub(crate) fn hash(&self) -> u64 {
+        zobrist::status_key(
+            self.turn,
+            self.get_player_turn(),
+            self.move_type,
+            self.is_end,
+        )
+    }
+
     pub fn set_end(&mut self, winner: Option<PlayerSymbol>) {
         self.is_end = true;
         self.winner = winner;
@@ -1,13 +1,26 @@
-#![feature(iterator_try_collect)]
+#![cfg_attr(test, feature(test))]
+
+#[cfg(test)]
+extern crate test;
 
 pub const DEFAULT_BOARD_SIZE: usize = 3;
+/// A mark takes two fields, so smaller boards can't be played.
+pub const MIN_BOARD_SIZE: usize = 2;
 
 pub mod board;
 pub mod cycle;
 pub mod field;
-mod field_coordinate;
+pub mod field_coordinate;
 pub mod game;
 mod game_status;
+pub mod line;
 mod move_type;
+pub mod notation;
 pub mod player_move;
 pub mod player_symbol;
+pub mod rules;
+pub mod score;
+pub mod symmetry;
+#[cfg(test)]
+mod test_util;
+mod zobrist;
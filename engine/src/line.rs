@@ -0,0 +1,65 @@
+use crate::field_coordinate::FieldCoordinate;
+use crate::player_symbol::PlayerSymbol;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Serialize, Deserialize, Debug, PartialEq)]
+pub enum LineDirection {
+    /// Row with the given `y`.
+    Row(usize),
+    /// Column with the given `x`.
+    Column(usize),
+    /// Diagonal going down and right on which `x - y` is the given offset,
+    /// 0 for the main diagonal.
+    Diagonal(isize),
+    /// Diagonal going down and left on which `x + y` is the given offset,
+    /// `size - 1` for the main anti-diagonal.
+    AntiDiagonal(usize),
+}
+
+/// Run of at least `win_length` fields collapsed to the same symbol.
+#[derive(Clone, Serialize, Deserialize, Debug, PartialEq)]
+pub struct Line {
+    direction: LineDirection,
+    player_symbol: PlayerSymbol,
+    fields: Vec<FieldCoordinate>,
+    max_turn: usize,
+}
+
+impl Line {
+    pub(crate) fn new(
+        direction: LineDirection,
+        player_symbol: PlayerSymbol,
+        fields: Vec<FieldCoordinate>,
+        max_turn: usize,
+    ) -> Self {
+        Line {
+            direction,
+            player_symbol,
+            fields,
+            max_turn,
+        }
+    }
+
+    #[must_use]
+    pub fn get_direction(&self) -> LineDirection {
+        self.direction
+    }
+
+    #[must_use]
+    pub fn get_player_symbol(&self) -> PlayerSymbol {
+        self.player_symbol
+    }
+
+    /// Fields of the line in order along its direction.
+    #[must_use]
+    pub fn get_fields(&self) -> &[FieldCoordinate] {
+        &self.fields
+    }
+
+    /// Highest turn subscript of the earliest completed segment of
+    /// `win_length` fields, deciding which line was completed first.
+    #[must_use]
+    pub fn get_max_turn(&self) -> usize {
+        self.max_turn
+    }
+}
@@ -0,0 +1,132 @@
+pub mod notation_error;
+#[cfg(test)]
+mod test;
+
+use crate::field::Field;
+use crate::game::Game;
+use crate::notation::notation_error::NotationError;
+use crate::player_move::Move;
+use crate::player_symbol::PlayerSymbol;
+use error_stack::{Report, Result};
+use std::fmt;
+use std::fmt::Formatter;
+use std::str::FromStr;
+
+/// Move written with the mark it makes or collapses to, subscripts count
+/// turns from 1:
+/// - `X3 a1-c2` X marks `a1` and `c2` in the third turn,
+/// - `c2=X3` the field `c2` collapses to the mark `X3`,
+/// - `X9 c3` X plays a classical mark on `c3` in the ninth turn.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MoveNotation {
+    player_symbol: PlayerSymbol,
+    turn: usize,
+    player_move: Move,
+}
+
+impl MoveNotation {
+    /// Notation of `player_move` made by the player on turn in `game`.
+    /// Returns None if a collapse names a field without a mark of that turn.
+    #[must_use]
+    pub fn new(game: &Game, player_move: Move) -> Option<Self> {
+        let status = game.get_status();
+        let (player_symbol, turn) = match player_move {
+            Move::Mark { .. } | Move::Classical { .. } => {
+                (status.get_player_turn(), status.get_turn())
+            }
+            Move::Collapse { field, index } => {
+                let positions = game.get_board().get_positions();
+                match positions.get(field.y, field.x)? {
+                    Field::Entangled(symbols) => ((*symbols.get(index)?)?, index),
+                    Field::Collapsed(..) => return None,
+                }
+            }
+        };
+        Some(MoveNotation {
+            player_symbol,
+            turn,
+            player_move,
+        })
+    }
+
+    #[must_use]
+    pub fn get_player_move(&self) -> Move {
+        self.player_move
+    }
+
+    /// Symbol of the mark made or collapsed to.
+    #[must_use]
+    pub fn get_player_symbol(&self) -> PlayerSymbol {
+        self.player_symbol
+    }
+
+    /// Turn of the mark made or collapsed to, counted from 0.
+    #[must_use]
+    pub fn get_turn(&self) -> usize {
+        self.turn
+    }
+}
+
+impl fmt::Display for MoveNotation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let mark = format!("{}{}", self.player_symbol, self.turn + 1);
+        match self.player_move {
+            Move::Mark { field1, field2 } => write!(f, "{mark} {field1}-{field2}"),
+            Move::Collapse { field, .. } => write!(f, "{field}={mark}"),
+            Move::Classical { field } => write!(f, "{mark} {field}"),
+        }
+    }
+}
+
+impl FromStr for MoveNotation {
+    type Err = Report<NotationError>;
+
+    fn from_str(s: &str) -> Result<Self, NotationError> {
+        if let Some((field, mark)) = s.trim().split_once('=') {
+            let field = field.parse()?;
+            let (player_symbol, turn) = parse_mark(mark)?;
+            return Ok(MoveNotation {
+                player_symbol,
+                turn,
+                player_move: Move::Collapse { field, index: turn },
+            });
+        }
+        let mut parts = s.split_whitespace();
+        let (Some(mark), Some(fields), None) = (parts.next(), parts.next(), parts.next()) else {
+            return Err(Report::new(NotationError {})
+                .attach_printable(format!("Move {s} should be a mark and fields")));
+        };
+        let (player_symbol, turn) = parse_mark(mark)?;
+        let player_move = match fields.split_once('-') {
+            Some((first, second)) => Move::Mark {
+                field1: first.parse()?,
+                field2: second.parse()?,
+            },
+            None => Move::Classical {
+                field: fields.parse()?,
+            },
+        };
+        Ok(MoveNotation {
+            player_symbol,
+            turn,
+            player_move,
+        })
+    }
+}
+
+/// Parses a symbol with its subscript, e.g. `X3`, into the symbol and the
+/// turn counted from 0.
+fn parse_mark(mark: &str) -> Result<(PlayerSymbol, usize), NotationError> {
+    let error = || {
+        Report::new(NotationError {})
+            .attach_printable(format!("Mark {mark} should be a symbol and a subscript"))
+    };
+    let player_symbol = match mark.get(..1) {
+        Some("X") => PlayerSymbol::X,
+        Some("O") => PlayerSymbol::O,
+        _ => return Err(error()),
+    };
+    let subscript = mark[1..].parse::<usize>().map_err(|_| error())?;
+    let turn = subscript.checked_sub(1).ok_or_else(error)?;
+    Ok((player_symbol, turn))
+}
@@ -0,0 +1,14 @@
+use core::fmt;
+use std::error::Error;
+use std::fmt::Formatter;
+
+#[derive(Debug)]
+pub struct NotationError {}
+
+impl fmt::Display for NotationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("Notation error")
+    }
+}
+
+impl Error for NotationError {}
@@ -0,0 +1,125 @@
+use super::*;
+use crate::field_coordinate::FieldCoordinate;
+use crate::score::Score;
+
+#[test]
+fn field_coordinate_round_trip() {
+    for (field_coordinate, text) in [
+        (FieldCoordinate { x: 0, y: 0 }, "a1"),
+        (FieldCoordinate { x: 2, y: 1 }, "c2"),
+        (FieldCoordinate { x: 25, y: 9 }, "z10"),
+        (FieldCoordinate { x: 26, y: 0 }, "aa1"),
+    ] {
+        assert_eq!(field_coordinate.to_string(), text);
+        assert_eq!(text.parse::<FieldCoordinate>().unwrap(), field_coordinate);
+    }
+}
+
+#[test]
+fn invalid_field_coordinate() {
+    for text in ["", "a", "1", "a0", "A1", "a1b", "-1"] {
+        assert!(text.parse::<FieldCoordinate>().is_err(), "{text}");
+    }
+}
+
+#[test]
+fn mark_notation() {
+    let notation = "X3 a1-c2".parse::<MoveNotation>().unwrap();
+    assert_eq!(notation.get_player_symbol(), PlayerSymbol::X);
+    assert_eq!(notation.get_turn(), 2);
+    assert_eq!(
+        notation.get_player_move(),
+        Move::Mark {
+            field1: FieldCoordinate { x: 0, y: 0 },
+            field2: FieldCoordinate { x: 2, y: 1 },
+        }
+    );
+    assert_eq!(notation.to_string(), "X3 a1-c2");
+}
+
+#[test]
+fn collapse_notation() {
+    let notation = "c2=O4".parse::<MoveNotation>().unwrap();
+    assert_eq!(notation.get_player_symbol(), PlayerSymbol::O);
+    assert_eq!(
+        notation.get_player_move(),
+        Move::Collapse {
+            field: FieldCoordinate { x: 2, y: 1 },
+            index: 3,
+        }
+    );
+    assert_eq!(notation.to_string(), "c2=O4");
+}
+
+#[test]
+fn classical_notation() {
+    let notation = "X9 c3".parse::<MoveNotation>().unwrap();
+    assert_eq!(
+        notation.get_player_move(),
+        Move::Classical {
+            field: FieldCoordinate { x: 2, y: 2 },
+        }
+    );
+    assert_eq!(notation.to_string(), "X9 c3");
+}
+
+#[test]
+fn invalid_notation() {
+    for text in [
+        "",
+        "X0 a1-b1",
+        "Y1 a1-b1",
+        "X1",
+        "X1 a1-b1 c1",
+        "a1=",
+        "a1=X",
+        "X1 a1-",
+    ] {
+        assert!(text.parse::<MoveNotation>().is_err(), "{text}");
+    }
+}
+
+#[test]
+fn notation_of_game_moves() {
+    let mut game = Game::new(3);
+    let mark = "X1 a1-b1"
+        .parse::<MoveNotation>()
+        .unwrap()
+        .get_player_move();
+    assert_eq!(
+        MoveNotation::new(&game, mark).unwrap().to_string(),
+        "X1 a1-b1"
+    );
+    game.player_move(mark, PlayerSymbol::X).unwrap();
+    let mark = "O2 a1-b1"
+        .parse::<MoveNotation>()
+        .unwrap()
+        .get_player_move();
+    assert_eq!(
+        MoveNotation::new(&game, mark).unwrap().to_string(),
+        "O2 a1-b1"
+    );
+    game.player_move(mark, PlayerSymbol::O).unwrap();
+
+    let collapse = Move::Collapse {
+        field: FieldCoordinate { x: 1, y: 0 },
+        index: 1,
+    };
+    assert_eq!(
+        MoveNotation::new(&game, collapse).unwrap().to_string(),
+        "b1=O2"
+    );
+    let missing_mark = Move::Collapse {
+        field: FieldCoordinate { x: 2, y: 0 },
+        index: 1,
+    };
+    assert_eq!(MoveNotation::new(&game, missing_mark), None);
+}
+
+#[test]
+fn score_round_trip() {
+    for score in [Score::new(0, 0), Score::new(2, 1), Score::new(3, 4)] {
+        assert_eq!(score.to_string().parse::<Score>().unwrap(), score);
+    }
+    assert!("X 1 : 1/2".parse::<Score>().is_err());
+}
@@ -2,7 +2,6 @@ use crate::field_coordinate::FieldCoordinate;
 use serde::{Deserialize, Serialize};
 
 #[derive(Deserialize, Serialize, Debug, Copy, Clone, PartialEq)]
-#[serde(tag = "type")]
 pub enum Move {
     Mark {
         field1: FieldCoordinate,
@@ -12,4 +11,6 @@ pub enum Move {
         field: FieldCoordinate,
         index: usize,
     },
+    /// Classical mark, allowed only on the last uncollapsed field.
+    Classical { field: FieldCoordinate },
 }
@@ -9,7 +9,8 @@ pub enum PlayerSymbol {
 }
 
 impl PlayerSymbol {
-    pub(super) fn opposite_symbol(player_symbol: PlayerSymbol) -> PlayerSymbol {
+    #[must_use]
+    pub fn opposite_symbol(player_symbol: PlayerSymbol) -> PlayerSymbol {
         match player_symbol {
             PlayerSymbol::O => PlayerSymbol::X,
             PlayerSymbol::X => PlayerSymbol::O,
@@ -0,0 +1,133 @@
+#[cfg(test)]
+mod test;
+
+use crate::game::game_result::EndReason;
+use crate::line::Line;
+use crate::player_symbol::PlayerSymbol;
+use crate::score::Score;
+use crate::zobrist;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+/// Decisions of a rule variant, consulted by [`Game`](crate::game::Game)
+/// while moves are made. Every method has the standard rule as default, so a
+/// variant only overrides what it changes. Who collapses a cycle is chosen
+/// separately by a [`CollapseChooser`].
+pub trait Rules: Send + Sync {
+    /// Every line is worth one point. When both players completed lines, the
+    /// player whose line has the higher maximum subscript gets only half a
+    /// point for each of their lines.
+    fn score(&self, lines: &[Line]) -> Score {
+        let first_line = lines
+            .iter()
+            .min_by_key(|line| line.get_max_turn())
+            .map(Line::get_player_symbol);
+        let mut score = Score::default();
+        for player_symbol in lines.iter().map(Line::get_player_symbol) {
+            if Some(player_symbol) == first_line {
+                score.add(player_symbol, 2);
+            } else {
+                score.add(player_symbol, 1);
+            }
+        }
+        score
+    }
+
+    /// Whether completing a line is good for its player, as bots judging an
+    /// unfinished game need to know.
+    fn rewards_lines(&self) -> bool {
+        true
+    }
+
+    /// Whether the last uncollapsed field is taken with a classical move.
+    /// Without it the game ends in a draw once one field is left.
+    fn allows_classical(&self) -> bool {
+        true
+    }
+
+    /// Checked after every collapse and classical move with the lines
+    /// completed on the board. The game ends when a line is completed or
+    /// when every field is collapsed, in which case it is a draw.
+    fn end(&self, lines: &[Line], uncollapsed_fields: usize) -> Option<(Score, EndReason)> {
+        if !lines.is_empty() {
+            return Some((self.score(lines), EndReason::Lines(lines.to_vec())));
+        }
+        let fields_left = usize::from(!self.allows_classical());
+        (uncollapsed_fields <= fields_left).then(|| (Score::default(), EndReason::BoardFull))
+    }
+}
+
+/// Rules of quantum tic-tac-toe as first described.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct StandardRules;
+
+impl Rules for StandardRules {}
+
+/// Completing a line loses: the points of every line go to the opponent of
+/// the player who completed it.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MisereRules;
+
+impl Rules for MisereRules {
+    fn score(&self, lines: &[Line]) -> Score {
+        let score = StandardRules.score(lines);
+        Score::new(
+            score.half_points(PlayerSymbol::O),
+            score.half_points(PlayerSymbol::X),
+        )
+    }
+
+    fn rewards_lines(&self) -> bool {
+        false
+    }
+}
+
+/// Player choosing the collapse of a cycle.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CollapseChooser {
+    /// The opponent of the player who closed the cycle.
+    #[default]
+    Opponent,
+    /// The player who closed the cycle.
+    Creator,
+    /// One of the players drawn from the seed and the turn, so a replayed
+    /// game draws the same players.
+    Random { seed: u64 },
+}
+
+impl CollapseChooser {
+    /// Player choosing how the cycle closed by `cycle_creator` in the given
+    /// turn collapses.
+    #[must_use]
+    pub fn collapse_player(self, cycle_creator: PlayerSymbol, turn: usize) -> PlayerSymbol {
+        match self {
+            CollapseChooser::Opponent => PlayerSymbol::opposite_symbol(cycle_creator),
+            CollapseChooser::Creator => cycle_creator,
+            CollapseChooser::Random { seed } => {
+                if zobrist::splitmix64(seed ^ zobrist::splitmix64(turn as u64)) & 1 == 0 {
+                    cycle_creator
+                } else {
+                    PlayerSymbol::opposite_symbol(cycle_creator)
+                }
+            }
+        }
+    }
+}
+
+/// Rule variants a game can be configured with.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RuleSet {
+    #[default]
+    Standard,
+    Misere,
+}
+
+impl RuleSet {
+    #[must_use]
+    pub fn rules(self) -> Arc<dyn Rules> {
+        match self {
+            RuleSet::Standard => Arc::new(StandardRules),
+            RuleSet::Misere => Arc::new(MisereRules),
+        }
+    }
+}
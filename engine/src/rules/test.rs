@@ -0,0 +1,194 @@
+use super::*;
+use crate::game::game_error::GameError;
+use crate::game::game_result::GameResult;
+use crate::game::Game;
+use crate::line::LineDirection;
+use crate::player_move::Move;
+use crate::test_util::{collapse, mark};
+
+struct NoClassical;
+
+impl Rules for NoClassical {
+    fn allows_classical(&self) -> bool {
+        false
+    }
+}
+
+/// Never ends the game.
+struct NoEnd;
+
+impl Rules for NoEnd {
+    fn end(&self, _lines: &[Line], _uncollapsed_fields: usize) -> Option<(Score, EndReason)> {
+        None
+    }
+}
+
+#[test]
+fn cycle_creator_collapses() {
+    let mut game = Game::new(3).with_collapse_chooser(CollapseChooser::Creator);
+    game.player_move(mark((0, 0), (1, 0)), PlayerSymbol::X)
+        .unwrap();
+    game.player_move(mark((0, 0), (1, 0)), PlayerSymbol::O)
+        .unwrap();
+    assert_eq!(game.get_status().get_player_turn(), PlayerSymbol::O);
+    assert_eq!(
+        *game
+            .player_move(collapse((0, 0), 0), PlayerSymbol::X)
+            .unwrap_err()
+            .current_context(),
+        GameError::PlayerTurnError
+    );
+    game.player_move(collapse((0, 0), 0), PlayerSymbol::O)
+        .unwrap();
+    // Marks keep alternating after the collapse
+    assert_eq!(game.get_status().get_player_turn(), PlayerSymbol::X);
+}
+
+#[test]
+fn standard_rule_set() {
+    for seed in 0..10 {
+        let mut standard = Game::new(3);
+        let mut configured = Game::with_rules(3, 3, RuleSet::Standard.rules()).unwrap();
+        for ply in 0.. {
+            let moves = standard.legal_moves().collect::<Vec<_>>();
+            assert_eq!(configured.legal_moves().collect::<Vec<_>>(), moves);
+            if moves.is_empty() {
+                break;
+            }
+            let player_move = moves[(seed * 7 + ply * 5) % moves.len()];
+            let player_symbol = standard.get_status().get_player_turn();
+            assert_eq!(
+                standard.player_move(player_move, player_symbol).unwrap(),
+                configured.player_move(player_move, player_symbol).unwrap()
+            );
+        }
+    }
+}
+
+#[test]
+fn draw_without_classical_move() {
+    let mut draws = 0;
+    for seed in 0..40 {
+        let mut game = Game::with_rules(3, 3, Arc::new(NoClassical)).unwrap();
+        let mut result = None;
+        for ply in 0.. {
+            let moves = game.legal_moves().collect::<Vec<_>>();
+            if moves.is_empty() {
+                break;
+            }
+            assert!(!matches!(moves[0], Move::Classical { .. }));
+            let player_symbol = game.get_status().get_player_turn();
+            result = Some(
+                game.player_move(moves[(seed * 7 + ply * 5) % moves.len()], player_symbol)
+                    .unwrap(),
+            );
+        }
+        if let Some(GameResult::GameEnd(score, EndReason::BoardFull)) = result {
+            assert_eq!(score, Score::default());
+            draws += 1;
+        }
+    }
+    assert!(draws > 0);
+}
+
+#[test]
+fn classical_move_without_end() {
+    let mut classical_moves = 0;
+    for seed in 0..20 {
+        let mut game = Game::with_rules(3, 3, Arc::new(NoEnd)).unwrap();
+        for ply in 0.. {
+            let moves = game.legal_moves().collect::<Vec<_>>();
+            if moves.is_empty() {
+                break;
+            }
+            let player_move = moves[(seed * 7 + ply * 5) % moves.len()];
+            let player_symbol = game.get_status().get_player_turn();
+            if let Move::Classical { .. } = player_move {
+                let snapshot = game.snapshot();
+                let result = game.player_move(player_move, player_symbol).unwrap_err();
+                assert_eq!(result.current_context(), &GameError::MissingGameEnd);
+                assert_eq!(game.snapshot(), snapshot);
+                classical_moves += 1;
+                break;
+            }
+            game.player_move(player_move, player_symbol).unwrap();
+        }
+    }
+    assert!(classical_moves > 0);
+}
+
+#[test]
+fn misere_line_loses() {
+    let mut game = Game::new(3).with_rule_set(RuleSet::Misere);
+    for (turn, column) in [(0, 0), (2, 1)] {
+        game.player_move(mark((column, 0), (column, 1)), PlayerSymbol::X)
+            .unwrap();
+        game.player_move(mark((column, 0), (column, 1)), PlayerSymbol::O)
+            .unwrap();
+        game.player_move(collapse((column, 0), turn), PlayerSymbol::X)
+            .unwrap();
+    }
+    game.player_move(mark((2, 0), (2, 2)), PlayerSymbol::X)
+        .unwrap();
+    game.player_move(mark((2, 0), (2, 2)), PlayerSymbol::O)
+        .unwrap();
+    let GameResult::GameEnd(score, EndReason::Lines(lines)) = game
+        .player_move(collapse((2, 0), 4), PlayerSymbol::X)
+        .unwrap()
+    else {
+        panic!("Game should end with a line");
+    };
+    assert_eq!(lines[0].get_player_symbol(), PlayerSymbol::X);
+    assert_eq!(score, Score::new(0, 2));
+    assert_eq!(score.winner(), Some(PlayerSymbol::O));
+}
+
+#[test]
+fn misere_score_of_both_players_lines() {
+    let line = |player_symbol, max_turn| {
+        Line::new(LineDirection::Row(0), player_symbol, Vec::new(), max_turn)
+    };
+    let lines = [line(PlayerSymbol::X, 4), line(PlayerSymbol::O, 7)];
+    assert_eq!(StandardRules.score(&lines), Score::new(2, 1));
+    // The player completing a line first loses by half a point
+    assert_eq!(MisereRules.score(&lines), Score::new(1, 2));
+    assert_eq!(MisereRules.score(&[]), Score::default());
+    assert!(StandardRules.rewards_lines());
+    assert!(!MisereRules.rewards_lines());
+}
+
+#[test]
+fn collapse_chooser_players() {
+    for turn in 0..9 {
+        assert_eq!(
+            CollapseChooser::Opponent.collapse_player(PlayerSymbol::X, turn),
+            PlayerSymbol::O
+        );
+        assert_eq!(
+            CollapseChooser::Creator.collapse_player(PlayerSymbol::O, turn),
+            PlayerSymbol::O
+        );
+    }
+    let random = CollapseChooser::Random { seed: 7 };
+    let players = (0..32)
+        .map(|turn| random.collapse_player(PlayerSymbol::X, turn))
+        .collect::<Vec<_>>();
+    assert!(players.contains(&PlayerSymbol::X));
+    assert!(players.contains(&PlayerSymbol::O));
+    assert!((0..32).all(|turn| random.collapse_player(PlayerSymbol::X, turn) == players[turn]));
+}
+
+#[test]
+fn rule_set_with_collapse_chooser() {
+    let mut game = Game::new(3)
+        .with_rule_set(RuleSet::Misere)
+        .with_collapse_chooser(CollapseChooser::Creator);
+    game.player_move(mark((0, 0), (1, 0)), PlayerSymbol::X)
+        .unwrap();
+    game.player_move(mark((0, 0), (1, 0)), PlayerSymbol::O)
+        .unwrap();
+    assert_eq!(game.get_status().get_player_turn(), PlayerSymbol::O);
+    game.player_move(collapse((0, 0), 0), PlayerSymbol::O)
+        .unwrap();
+    assert_eq!(game.get_status().get_player_turn(), PlayerSymbol::X);
+}
@@ -0,0 +1,96 @@
+use crate::notation::notation_error::NotationError;
+use crate::player_symbol::PlayerSymbol;
+use error_stack::{Report, Result};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::fmt::Formatter;
+use std::str::FromStr;
+
+/// Points of both players counted in halves, as a line completed later than
+/// the opponent's line is worth only half a point.
+#[derive(Copy, Clone, PartialEq, Eq, Default, Serialize, Deserialize, Debug)]
+pub struct Score {
+    x: usize,
+    o: usize,
+}
+
+impl Score {
+    #[must_use]
+    pub fn new(x_half_points: usize, o_half_points: usize) -> Self {
+        Score {
+            x: x_half_points,
+            o: o_half_points,
+        }
+    }
+
+    /// Score of a game decided without lines, with one point for the winner.
+    #[must_use]
+    pub fn from_winner(winner: Option<PlayerSymbol>) -> Self {
+        let mut score = Score::default();
+        if let Some(winner) = winner {
+            score.add(winner, 2);
+        }
+        score
+    }
+
+    #[must_use]
+    pub fn half_points(&self, player_symbol: PlayerSymbol) -> usize {
+        match player_symbol {
+            PlayerSymbol::X => self.x,
+            PlayerSymbol::O => self.o,
+        }
+    }
+
+    #[must_use]
+    pub fn winner(&self) -> Option<PlayerSymbol> {
+        if self.x > self.o {
+            return Some(PlayerSymbol::X);
+        }
+        if self.o > self.x {
+            return Some(PlayerSymbol::O);
+        }
+        None
+    }
+
+    pub(crate) fn add(&mut self, player_symbol: PlayerSymbol, half_points: usize) {
+        match player_symbol {
+            PlayerSymbol::X => self.x += half_points,
+            PlayerSymbol::O => self.o += half_points,
+        }
+    }
+}
+
+impl fmt::Display for Score {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let points = |half_points: usize| match (half_points / 2, half_points % 2) {
+            (0, 1) => "1/2".to_string(),
+            (points, 0) => points.to_string(),
+            (points, _) => format!("{points} 1/2"),
+        };
+        write!(f, "X {} : O {}", points(self.x), points(self.o))
+    }
+}
+
+/// Parses the score as displayed, e.g. `X 1 : O 1/2`.
+impl FromStr for Score {
+    type Err = Report<NotationError>;
+
+    fn from_str(s: &str) -> Result<Self, NotationError> {
+        let error = || {
+            Report::new(NotationError {}).attach_printable(format!("Score {s} should be X _ : O _"))
+        };
+        let half_points = |points: &str| match points.trim().split_once(' ') {
+            Some((whole, "1/2")) => whole.parse::<usize>().ok().map(|whole| whole * 2 + 1),
+            Some(_) => None,
+            None if points.trim() == "1/2" => Some(1),
+            None => points.trim().parse::<usize>().ok().map(|whole| whole * 2),
+        };
+        let (x, o) = s.trim().split_once(':').ok_or_else(error)?;
+        let x = x.trim().strip_prefix('X').and_then(half_points);
+        let o = o.trim().strip_prefix('O').and_then(half_points);
+        match (x, o) {
+            (Some(x), Some(o)) => Ok(Score::new(x, o)),
+            _ => Err(error()),
+        }
+    }
+}
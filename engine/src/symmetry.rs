@@ -0,0 +1,79 @@
+use crate::field_coordinate::FieldCoordinate;
+use crate::player_move::Move;
+use serde::{Deserialize, Serialize};
+
+/// Rotations and reflections of a square board.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Symmetry {
+    Identity,
+    /// Quarter turn clockwise.
+    Rotate90,
+    Rotate180,
+    Rotate270,
+    /// Mirror of the columns.
+    FlipHorizontal,
+    /// Mirror of the rows.
+    FlipVertical,
+    /// Reflection in the diagonal from `a1`.
+    FlipDiagonal,
+    FlipAntiDiagonal,
+}
+
+impl Symmetry {
+    pub const ALL: [Symmetry; 8] = [
+        Symmetry::Identity,
+        Symmetry::Rotate90,
+        Symmetry::Rotate180,
+        Symmetry::Rotate270,
+        Symmetry::FlipHorizontal,
+        Symmetry::FlipVertical,
+        Symmetry::FlipDiagonal,
+        Symmetry::FlipAntiDiagonal,
+    ];
+
+    /// Symmetry taking the board back to where it was before this one.
+    #[must_use]
+    pub fn inverse(self) -> Symmetry {
+        match self {
+            Symmetry::Rotate90 => Symmetry::Rotate270,
+            Symmetry::Rotate270 => Symmetry::Rotate90,
+            symmetry => symmetry,
+        }
+    }
+
+    /// Field the given field of a board of the given size is moved to.
+    #[must_use]
+    pub fn apply(self, field_coordinate: FieldCoordinate, size: usize) -> FieldCoordinate {
+        let FieldCoordinate { x, y } = field_coordinate;
+        let last = size - 1;
+        let (x, y) = match self {
+            Symmetry::Identity => (x, y),
+            Symmetry::Rotate90 => (last - y, x),
+            Symmetry::Rotate180 => (last - x, last - y),
+            Symmetry::Rotate270 => (y, last - x),
+            Symmetry::FlipHorizontal => (last - x, y),
+            Symmetry::FlipVertical => (x, last - y),
+            Symmetry::FlipDiagonal => (y, x),
+            Symmetry::FlipAntiDiagonal => (last - y, last - x),
+        };
+        FieldCoordinate { x, y }
+    }
+
+    /// The same move made on the transformed board.
+    #[must_use]
+    pub fn apply_move(self, player_move: Move, size: usize) -> Move {
+        match player_move {
+            Move::Mark { field1, field2 } => Move::Mark {
+                field1: self.apply(field1, size),
+                field2: self.apply(field2, size),
+            },
+            Move::Collapse { field, index } => Move::Collapse {
+                field: self.apply(field, size),
+                index,
+            },
+            Move::Classical { field } => Move::Classical {
+                field: self.apply(field, size),
+            },
+        }
+    }
+}
@@ -0,0 +1,16 @@
+use crate::field_coordinate::FieldCoordinate;
+use crate::player_move::Move;
+
+pub(crate) fn mark((x1, y1): (usize, usize), (x2, y2): (usize, usize)) -> Move {
+    Move::Mark {
+        field1: FieldCoordinate { x: x1, y: y1 },
+        field2: FieldCoordinate { x: x2, y: y2 },
+    }
+}
+
+pub(crate) fn collapse((x, y): (usize, usize), index: usize) -> Move {
+    Move::Collapse {
+        field: FieldCoordinate { x, y },
+        index,
+    }
+}
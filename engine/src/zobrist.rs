@@ -0,0 +1,65 @@
+//! Keys of the Zobrist hash of a position. Keys are derived from what they
+//! stand for instead of drawn at random, so hashes are stable across runs and
+//! can be stored.
+
+use crate::move_type::MoveType;
+use crate::player_symbol::PlayerSymbol;
+
+const MARK: u64 = 1;
+const COLLAPSED: u64 = 2;
+const CYCLE: u64 = 3;
+const STATUS: u64 = 4;
+
+/// Spooky mark of the given turn on the field.
+pub(crate) fn mark_key(field: usize, player_symbol: PlayerSymbol, turn: usize) -> u64 {
+    key(MARK, field, symbol_value(player_symbol), turn)
+}
+
+/// Field collapsed to the mark of the given turn.
+pub(crate) fn collapsed_key(field: usize, player_symbol: PlayerSymbol, turn: usize) -> u64 {
+    key(COLLAPSED, field, symbol_value(player_symbol), turn)
+}
+
+/// Field being a part of the cycle waiting to be collapsed.
+pub(crate) fn cycle_key(field: usize) -> u64 {
+    key(CYCLE, field, 0, 0)
+}
+
+pub(crate) fn status_key(
+    turn: usize,
+    player_turn: PlayerSymbol,
+    move_type: MoveType,
+    is_end: bool,
+) -> u64 {
+    let move_type = match move_type {
+        MoveType::Mark => 0,
+        MoveType::Collapse => 1,
+        MoveType::Classical => 2,
+    };
+    key(
+        STATUS,
+        move_type * 2 + usize::from(is_end),
+        symbol_value(player_turn),
+        turn,
+    )
+}
+
+fn symbol_value(player_symbol: PlayerSymbol) -> u64 {
+    match player_symbol {
+        PlayerSymbol::X => 0,
+        PlayerSymbol::O => 1,
+    }
+}
+
+fn key(feature: u64, field: usize, player_symbol: u64, turn: usize) -> u64 {
+    splitmix64(feature << 60 ^ (field as u64) << 32 ^ (turn as u64) << 1 ^ player_symbol)
+}
+
+/// Finalizer of the `SplitMix64` generator, spreads every input bit over the
+/// whole key.
+pub(crate) fn splitmix64(value: u64) -> u64 {
+    let mut value = value.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    value = (value ^ (value >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    value = (value ^ (value >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    value ^ (value >> 31)
+}
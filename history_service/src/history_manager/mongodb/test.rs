@@ -1,5 +1,6 @@
 use crate::history_manager::mongodb::MongodbHistory;
 use crate::history_manager::HistoryManager;
+use ipc::game_configuration::GameConfiguration;
 use ipc::game_history::GameHistory;
 use ipc::moves_history::MovesHistory;
 use testcontainers::core::{IntoContainerPort, WaitFor};
@@ -25,7 +26,7 @@ async fn insert_empty_game_history() {
     let ports = container.ports().await.unwrap();
     let port = ports.map_to_host_port_ipv4(MONGODB_PORT.tcp()).unwrap();
     let mongodb_connection_string = format!("{ADDRESS}:{port}");
-    let move_history = MovesHistory::new(UUID, 3);
+    let move_history = MovesHistory::new(UUID, GameConfiguration::default());
     let history_manager = MongodbHistory::new(&mongodb_connection_string)
         .await
         .unwrap();
@@ -44,7 +45,7 @@ async fn get_empty_game_history() {
     let ports = container.ports().await.unwrap();
     let port = ports.map_to_host_port_ipv4(MONGODB_PORT.tcp()).unwrap();
     let mongodb_connection_string = format!("{ADDRESS}:{port}");
-    let move_history = MovesHistory::new(UUID, 3);
+    let move_history = MovesHistory::new(UUID, GameConfiguration::default());
     let history_manager = MongodbHistory::new(&mongodb_connection_string)
         .await
         .unwrap();
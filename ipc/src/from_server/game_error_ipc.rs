@@ -1,3 +1,4 @@
+use engine::board::board_error::BoardErrorKind;
 use engine::game::game_error;
 use serde::{Deserialize, Serialize};
 
@@ -6,7 +7,13 @@ pub enum GameError {
     PlayerTurnError,
     MoveTypeError,
     MoveAfterEnd,
-    MakingMoveError,
+    MakingMoveError(BoardErrorKind),
+    NothingToUndo,
+    NothingToRedo,
+    InvalidSnapshot,
+    InvalidSize,
+    InvalidWinLength,
+    MissingGameEnd,
 }
 
 impl From<&game_error::GameError> for GameError {
@@ -15,7 +22,13 @@ impl From<&game_error::GameError> for GameError {
             game_error::GameError::PlayerTurnError => GameError::PlayerTurnError,
             game_error::GameError::MoveTypeError => GameError::MoveTypeError,
             game_error::GameError::MoveAfterEnd => GameError::MoveAfterEnd,
-            game_error::GameError::MakingMoveError => GameError::MakingMoveError,
+            game_error::GameError::MakingMoveError(kind) => GameError::MakingMoveError(*kind),
+            game_error::GameError::NothingToUndo => GameError::NothingToUndo,
+            game_error::GameError::NothingToRedo => GameError::NothingToRedo,
+            game_error::GameError::InvalidSnapshot => GameError::InvalidSnapshot,
+            game_error::GameError::InvalidSize => GameError::InvalidSize,
+            game_error::GameError::InvalidWinLength => GameError::InvalidWinLength,
+            game_error::GameError::MissingGameEnd => GameError::MissingGameEnd,
         }
     }
 }
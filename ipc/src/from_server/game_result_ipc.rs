@@ -1,6 +1,7 @@
 use engine::cycle::Cycle;
 use engine::game::game_result;
-use engine::player_symbol::PlayerSymbol;
+use engine::game::game_result::EndReason;
+use engine::score::Score;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -8,7 +9,7 @@ pub enum GameResult {
     NextTurn,
     TurnAfterCollapse,
     NextTurnCycle(Cycle),
-    GameEnd(Option<PlayerSymbol>),
+    GameEnd(Score, EndReason),
 }
 
 impl From<&game_result::GameResult> for GameResult {
@@ -17,7 +18,9 @@ impl From<&game_result::GameResult> for GameResult {
             game_result::GameResult::NextTurn => GameResult::NextTurn,
             game_result::GameResult::TurnAfterCollapse => GameResult::TurnAfterCollapse,
             game_result::GameResult::NextTurnCycle(v) => GameResult::NextTurnCycle(v.clone()),
-            game_result::GameResult::GameEnd(v) => GameResult::GameEnd(*v),
+            game_result::GameResult::GameEnd(score, reason) => {
+                GameResult::GameEnd(*score, reason.clone())
+            }
         }
     }
 }
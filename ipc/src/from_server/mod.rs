@@ -7,6 +7,7 @@ use crate::player_assignment::PlayerAssignment;
 
 use crate::from_server::board_ipc::Board;
 use crate::from_server::game_result_ipc::GameResult;
+use engine::game::game_result::EndReason;
 use engine::player_symbol::PlayerSymbol;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
@@ -22,6 +23,8 @@ pub enum FromServer {
     MoveOk(GameResult),
     MoveErr(GameError),
     GameCrash,
-    GameEnded(Option<PlayerSymbol>),
+    /// Winner of the game and why it ended, with the completed lines of a
+    /// game ended by its last move.
+    GameEnded(Option<PlayerSymbol>, EndReason),
     EndOfTime(PlayerSymbol),
 }
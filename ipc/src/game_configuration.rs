@@ -1,4 +1,9 @@
 use crate::player_enum::Player;
+use engine::game::game_error::GameError;
+use engine::game::Game;
+use engine::rules::{CollapseChooser, RuleSet};
+use engine::MIN_BOARD_SIZE;
+use error_stack::{Report, Result};
 #[cfg(not(test))]
 use rand::Rng;
 use serde::{Deserialize, Serialize};
@@ -8,22 +13,27 @@ const DEFAULT_GAME_SIZE: usize = 3;
 const DEFAULT_BASED_TIME: Duration = Duration::from_mins(5);
 const DEFAULT_INCREMENT: Duration = Duration::from_secs(1);
 
-#[derive(Serialize, Deserialize, Debug, Clone, Copy)]
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
 pub struct GameConfiguration {
     size: usize,
+    win_length: usize,
     based_time: Duration,
     increment: Duration,
     first_player: Player,
+    rule_set: RuleSet,
+    collapse_chooser: CollapseChooser,
 }
 
 impl Default for GameConfiguration {
     fn default() -> Self {
         Self::new(
             DEFAULT_GAME_SIZE,
+            None,
             DEFAULT_BASED_TIME,
             DEFAULT_INCREMENT,
             Some(Player::Player1),
         )
+        .expect("Default configuration should be valid")
     }
 }
 
@@ -33,13 +43,41 @@ impl GameConfiguration {
         self.size
     }
 
+    /// Number of collapsed fields in a row needed to complete a line.
     #[must_use]
+    pub fn win_length(&self) -> usize {
+        self.win_length
+    }
+
+    /// Creates configuration of a game. Without `win_length` a line has to
+    /// span the whole board.
+    ///
+    /// # Errors
+    ///
+    /// Will return Err if the board is smaller than [`MIN_BOARD_SIZE`] or
+    /// `win_length` is zero or longer than the board.
     pub fn new(
         size: usize,
+        win_length: Option<usize>,
         based_time: Duration,
         increment: Duration,
         first_player: Option<Player>,
-    ) -> Self {
+    ) -> Result<Self, GameError> {
+        if size < MIN_BOARD_SIZE {
+            return Err(
+                Report::new(GameError::InvalidSize).attach_printable(format!(
+                    "Board of size {size} should be at least {MIN_BOARD_SIZE} fields wide"
+                )),
+            );
+        }
+        let win_length = win_length.unwrap_or(size);
+        if !(1..=size).contains(&win_length) {
+            return Err(
+                Report::new(GameError::InvalidWinLength).attach_printable(format!(
+                    "Win length {win_length} should be between 1 and {size}"
+                )),
+            );
+        }
         let first_player = if let Some(first_player) = first_player {
             first_player
         } else {
@@ -54,12 +92,22 @@ impl GameConfiguration {
             #[cfg(test)]
             Player::Player1
         };
-        Self {
+        Ok(Self {
             size,
+            win_length,
             based_time,
             increment,
             first_player,
-        }
+            rule_set: RuleSet::default(),
+            collapse_chooser: CollapseChooser::default(),
+        })
+    }
+
+    /// Plays the game by a rule variant instead of the standard rules.
+    #[must_use]
+    pub fn with_rule_set(mut self, rule_set: RuleSet) -> Self {
+        self.rule_set = rule_set;
+        self
     }
 
     #[must_use]
@@ -76,4 +124,32 @@ impl GameConfiguration {
     pub fn first_player(&self) -> &Player {
         &self.first_player
     }
+
+    /// Lets another player than the opponent of the cycle creator choose
+    /// the collapse.
+    #[must_use]
+    pub fn with_collapse_chooser(mut self, collapse_chooser: CollapseChooser) -> Self {
+        self.collapse_chooser = collapse_chooser;
+        self
+    }
+
+    #[must_use]
+    pub fn rule_set(&self) -> RuleSet {
+        self.rule_set
+    }
+
+    #[must_use]
+    pub fn collapse_chooser(&self) -> CollapseChooser {
+        self.collapse_chooser
+    }
+
+    /// # Errors
+    ///
+    /// Will return Err if the configuration was received with a board or a
+    /// win length that can't be played.
+    pub fn create_game(&self) -> Result<Game, GameError> {
+        Ok(Game::with_win_length(self.size, self.win_length)?
+            .with_rule_set(self.rule_set)
+            .with_collapse_chooser(self.collapse_chooser))
+    }
 }
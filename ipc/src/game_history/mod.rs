@@ -4,10 +4,9 @@ pub mod mongo_doc;
 use crate::from_server::board_ipc::Board;
 use crate::game_history::game_history_error::GameHistoryError;
 use crate::moves_history::MovesHistory;
-use engine::game::Game;
 use engine::player_move::Move;
 use engine::player_symbol::PlayerSymbol;
-use error_stack::Report;
+use error_stack::{Report, ResultExt};
 use serde::{Deserialize, Serialize};
 use std::time::Duration;
 use uuid::Uuid;
@@ -23,7 +22,10 @@ pub struct GameHistory {
 impl TryFrom<&MovesHistory> for GameHistory {
     type Error = Report<GameHistoryError>;
     fn try_from(value: &MovesHistory) -> Result<Self, Self::Error> {
-        let mut game = Game::new(value.board_size());
+        let mut game = value
+            .game_configuration()
+            .create_game()
+            .change_context(GameHistoryError {})?;
         let boards = value
             .moves()
             .iter()
@@ -0,0 +1,13 @@
+use core::fmt;
+use std::error::Error;
+use std::fmt::Formatter;
+
+#[derive(Debug)]
+pub struct GameRecordError {}
+
+impl fmt::Display for GameRecordError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("Game record Error")
+    }
+}
+impl Error for GameRecordError {}
@@ -0,0 +1,337 @@
+pub mod game_record_error;
+#[cfg(test)]
+mod test;
+
+use crate::game_configuration::GameConfiguration;
+use crate::game_record::game_record_error::GameRecordError;
+use crate::moves_history::MovesHistory;
+use crate::player_enum::Player;
+use engine::game::game_result::GameResult;
+use engine::notation::MoveNotation;
+use engine::player_move::Move;
+use engine::player_symbol::PlayerSymbol;
+use engine::rules::{CollapseChooser, RuleSet};
+use engine::score::Score;
+use error_stack::{Report, Result, ResultExt};
+use std::fmt;
+use std::fmt::Formatter;
+use std::str::FromStr;
+use std::time::Duration;
+
+const LINE_LENGTH: usize = 80;
+
+/// Game written as text, headers with the configuration and the result
+/// followed by the moves in notation:
+///
+/// ```text
+/// [Size "3"]
+/// [WinLength "3"]
+/// [TimeControl "300+1"]
+/// [FirstPlayer "Player1"]
+/// [Result "X 1 : O 0"]
+///
+/// X1 a1-b1 O2 a1-b1 b1=X1 ...
+/// ```
+///
+/// The result is `*` for an unfinished game and has to match the moves,
+/// unless they stop before the end of an interrupted game. Games played by a rule variant
+/// also have a `Rules` header and games with cycles collapsed by another
+/// player than the opponent of the creator a `CollapseChooser` header, e.g.
+/// `Creator` or `Random 42` with the seed.
+#[derive(Debug, PartialEq)]
+pub struct GameRecord {
+    game_configuration: GameConfiguration,
+    moves: Vec<(MoveNotation, PlayerSymbol)>,
+    result: Option<Score>,
+}
+
+impl GameRecord {
+    /// Replays the moves to check them and write them in notation. A result
+    /// of moves not ending the game records an interrupted game.
+    ///
+    /// # Errors
+    ///
+    /// Will return Err if a move can't be made or the moves end the game
+    /// with another result.
+    pub fn new(
+        game_configuration: GameConfiguration,
+        moves: &[(Move, PlayerSymbol)],
+        result: Option<Score>,
+    ) -> Result<Self, GameRecordError> {
+        Self::replay(game_configuration, moves)?.with_result(result)
+    }
+
+    #[must_use]
+    pub fn game_configuration(&self) -> &GameConfiguration {
+        &self.game_configuration
+    }
+
+    #[must_use]
+    pub fn moves(&self) -> Vec<(Move, PlayerSymbol)> {
+        self.moves
+            .iter()
+            .map(|(notation, player_symbol)| (notation.get_player_move(), *player_symbol))
+            .collect()
+    }
+
+    /// Final score, None if the game is unfinished.
+    #[must_use]
+    pub fn result(&self) -> Option<Score> {
+        self.result
+    }
+
+    /// Keeps the result of an interrupted game, the result of a game ended
+    /// by its moves has to be the one replayed.
+    fn with_result(self, result: Option<Score>) -> Result<Self, GameRecordError> {
+        match (self.result, result) {
+            (None, result) => Ok(Self { result, ..self }),
+            (Some(replayed), Some(result)) if replayed == result => Ok(self),
+            (Some(replayed), result) => {
+                Err(Report::new(GameRecordError {}).attach_printable(format!(
+                    "Result {} should be {replayed}, the moves ended the game",
+                    result.map_or_else(|| "*".to_string(), |result| result.to_string())
+                )))
+            }
+        }
+    }
+
+    /// Writes the moves in notation while making them, with the score of the
+    /// game if they end it.
+    fn replay(
+        game_configuration: GameConfiguration,
+        moves: &[(Move, PlayerSymbol)],
+    ) -> Result<Self, GameRecordError> {
+        let mut game = game_configuration
+            .create_game()
+            .change_context(GameRecordError {})?;
+        let mut result = None;
+        let moves = moves
+            .iter()
+            .map(|&(player_move, player_symbol)| {
+                let notation = MoveNotation::new(&game, player_move).ok_or_else(|| {
+                    Report::new(GameRecordError {})
+                        .attach_printable("Collapse should name an existing mark")
+                })?;
+                if let GameResult::GameEnd(score, _) = game
+                    .player_move(player_move, player_symbol)
+                    .change_context(GameRecordError {})?
+                {
+                    result = Some(score);
+                }
+                Ok((notation, player_symbol))
+            })
+            .collect::<Result<Vec<_>, GameRecordError>>()?;
+        Ok(Self {
+            game_configuration,
+            moves,
+            result,
+        })
+    }
+}
+
+impl TryFrom<&MovesHistory> for GameRecord {
+    type Error = Report<GameRecordError>;
+    fn try_from(value: &MovesHistory) -> Result<Self, GameRecordError> {
+        Self::replay(*value.game_configuration(), value.moves())
+    }
+}
+
+impl fmt::Display for GameRecord {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let configuration = &self.game_configuration;
+        writeln!(f, "[Size \"{}\"]", configuration.size())?;
+        writeln!(f, "[WinLength \"{}\"]", configuration.win_length())?;
+        writeln!(
+            f,
+            "[TimeControl \"{}+{}\"]",
+            configuration.based_time().as_secs(),
+            configuration.increment().as_secs()
+        )?;
+        writeln!(f, "[FirstPlayer \"{:?}\"]", configuration.first_player())?;
+        if configuration.rule_set() != RuleSet::Standard {
+            writeln!(f, "[Rules \"{:?}\"]", configuration.rule_set())?;
+        }
+        match configuration.collapse_chooser() {
+            CollapseChooser::Opponent => {}
+            CollapseChooser::Creator => writeln!(f, "[CollapseChooser \"Creator\"]")?,
+            CollapseChooser::Random { seed } => {
+                writeln!(f, "[CollapseChooser \"Random {seed}\"]")?;
+            }
+        }
+        match self.result {
+            Some(score) => writeln!(f, "[Result \"{score}\"]")?,
+            None => writeln!(f, "[Result \"*\"]")?,
+        }
+        writeln!(f)?;
+        let mut line = String::new();
+        for (notation, _) in &self.moves {
+            let notation = notation.to_string();
+            if !line.is_empty() && line.len() + notation.len() >= LINE_LENGTH {
+                writeln!(f, "{line}")?;
+                line.clear();
+            }
+            if !line.is_empty() {
+                line.push(' ');
+            }
+            line.push_str(&notation);
+        }
+        if !line.is_empty() {
+            writeln!(f, "{line}")?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for GameRecord {
+    type Err = Report<GameRecordError>;
+
+    fn from_str(s: &str) -> Result<Self, GameRecordError> {
+        let error = |message: String| Report::new(GameRecordError {}).attach_printable(message);
+        let mut size = None;
+        let mut win_length = None;
+        let mut time_control = None;
+        let mut first_player = None;
+        let mut rule_set = RuleSet::Standard;
+        let mut collapse_chooser = CollapseChooser::Opponent;
+        let mut result = None;
+        let mut movetext = Vec::new();
+        for line in s.lines().map(str::trim) {
+            let Some(header) = line.strip_prefix('[') else {
+                movetext.push(line);
+                continue;
+            };
+            let Some((name, value)) = header
+                .strip_suffix(']')
+                .and_then(|header| header.split_once(' '))
+                .and_then(|(name, value)| {
+                    Some((name, value.trim().strip_prefix('"')?.strip_suffix('"')?))
+                })
+            else {
+                return Err(error(format!("Header {line} should be [Name \"value\"]")));
+            };
+            let number = |value: &str| {
+                value
+                    .parse::<u64>()
+                    .map_err(|_| error(format!("Header {name} should be a number")))
+            };
+            match name {
+                "Size" => size = Some(number(value)?),
+                "WinLength" => win_length = Some(number(value)?),
+                "TimeControl" => {
+                    let (based_time, increment) = value
+                        .split_once('+')
+                        .ok_or_else(|| error("TimeControl should be based+increment".into()))?;
+                    time_control = Some((
+                        Duration::from_secs(number(based_time)?),
+                        Duration::from_secs(number(increment)?),
+                    ));
+                }
+                "FirstPlayer" => {
+                    first_player = Some(match value {
+                        "Player1" => Player::Player1,
+                        "Player2" => Player::Player2,
+                        _ => return Err(error(format!("Unknown first player {value}"))),
+                    });
+                }
+                "Rules" => {
+                    rule_set = match value {
+                        "Standard" => RuleSet::Standard,
+                        "Misere" => RuleSet::Misere,
+                        _ => return Err(error(format!("Unknown rules {value}"))),
+                    };
+                }
+                "CollapseChooser" => {
+                    collapse_chooser = match value.split_once(' ') {
+                        None if value == "Opponent" => CollapseChooser::Opponent,
+                        None if value == "Creator" => CollapseChooser::Creator,
+                        Some(("Random", seed)) => CollapseChooser::Random {
+                            seed: number(seed)?,
+                        },
+                        _ => return Err(error(format!("Unknown collapse chooser {value}"))),
+                    };
+                }
+                "Result" if value == "*" => result = Some(None),
+                "Result" => {
+                    result = Some(Some(
+                        value.parse::<Score>().change_context(GameRecordError {})?,
+                    ));
+                }
+                // Headers of other tools are kept out of the record
+                _ => {}
+            }
+        }
+
+        let to_usize =
+            |value: u64| usize::try_from(value).map_err(|_| error(format!("{value} is too large")));
+        let default = GameConfiguration::default();
+        let size = size.map(to_usize).transpose()?.unwrap_or(default.size());
+        let win_length = win_length.map(to_usize).transpose()?;
+        let (based_time, increment) =
+            time_control.unwrap_or((default.based_time(), default.increment()));
+        let game_configuration = GameConfiguration::new(
+            size,
+            win_length,
+            based_time,
+            increment,
+            Some(first_player.unwrap_or(*default.first_player())),
+        )
+        .change_context(GameRecordError {})?
+        .with_rule_set(rule_set)
+        .with_collapse_chooser(collapse_chooser);
+
+        let game_record = replay_movetext(game_configuration, &movetext)?;
+        // Without a Result header the record ends as the moves end the game
+        match result {
+            Some(result) => game_record.with_result(result),
+            None => Ok(game_record),
+        }
+    }
+}
+
+/// Reads the moves of the movetext, checking each of them on the game, with
+/// the result they end the game with.
+fn replay_movetext(
+    game_configuration: GameConfiguration,
+    movetext: &[&str],
+) -> Result<GameRecord, GameRecordError> {
+    let error = |message: String| Report::new(GameRecordError {}).attach_printable(message);
+    let mut game = game_configuration
+        .create_game()
+        .change_context(GameRecordError {})?;
+    let mut moves = Vec::new();
+    let mut result = None;
+    let mut tokens = movetext.iter().flat_map(|line| line.split_whitespace());
+    while let Some(token) = tokens.next() {
+        let notation = if token.contains('=') {
+            token.to_string()
+        } else {
+            let fields = tokens
+                .next()
+                .ok_or_else(|| error(format!("Mark {token} should be followed by fields")))?;
+            format!("{token} {fields}")
+        };
+        let notation = notation
+            .parse::<MoveNotation>()
+            .change_context(GameRecordError {})?;
+        let player_move = notation.get_player_move();
+        if MoveNotation::new(&game, player_move) != Some(notation) {
+            return Err(error(format!("Move {notation} doesn't fit the game")));
+        }
+        let player_symbol = match player_move {
+            Move::Collapse { .. } => game.get_status().get_player_turn(),
+            Move::Mark { .. } | Move::Classical { .. } => notation.get_player_symbol(),
+        };
+        if let GameResult::GameEnd(score, _) = game
+            .player_move(player_move, player_symbol)
+            .change_context(GameRecordError {})?
+        {
+            result = Some(score);
+        }
+        moves.push((notation, player_symbol));
+    }
+    Ok(GameRecord {
+        game_configuration,
+        moves,
+        result,
+    })
+}
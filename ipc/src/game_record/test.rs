@@ -0,0 +1,165 @@
+use super::*;
+use engine::field_coordinate::FieldCoordinate;
+use engine::game::game_error::GameError;
+use engine::rules::CollapseChooser;
+use uuid::Uuid;
+
+const DRAWN_GAME: &str = "[Size \"3\"]
+[WinLength \"3\"]
+[TimeControl \"300+1\"]
+[FirstPlayer \"Player1\"]
+[Result \"X 0 : O 0\"]
+
+X1 a1-b1 O2 a1-b1 a1=X1 X3 c1-b2 O4 c1-b2 c1=X3 X5 a2-c2 O6 a2-c2 a2=X5 X7 b3-a3
+O8 b3-a3 b3=X7 X9 c3
+";
+
+#[test]
+fn parse_and_write_game() {
+    let game_record = DRAWN_GAME.parse::<GameRecord>().unwrap();
+    assert_eq!(game_record.result(), Some(Score::new(0, 0)));
+    assert_eq!(game_record.moves().len(), 13);
+    assert_eq!(
+        game_record.moves()[2],
+        (
+            Move::Collapse {
+                field: FieldCoordinate { x: 0, y: 0 },
+                index: 0,
+            },
+            PlayerSymbol::X
+        )
+    );
+    assert_eq!(game_record.to_string(), DRAWN_GAME);
+}
+
+#[test]
+fn result_of_moves() {
+    let game_record = DRAWN_GAME.parse::<GameRecord>().unwrap();
+    let configuration = *game_record.game_configuration();
+    let moves = game_record.moves();
+    assert!(GameRecord::new(configuration, &moves, Some(Score::new(0, 0))).is_ok());
+    for result in [None, Some(Score::new(2, 0))] {
+        assert!(GameRecord::new(configuration, &moves, result).is_err());
+    }
+    // A game interrupted before its moves ended it keeps its result
+    let game_record = GameRecord::new(configuration, &moves[..4], Some(Score::new(0, 2))).unwrap();
+    assert_eq!(game_record.result(), Some(Score::new(0, 2)));
+}
+
+#[test]
+fn parse_result_of_moves() {
+    let wrong_result = DRAWN_GAME.replace("X 0 : O 0", "X 1 : O 0");
+    assert!(wrong_result.parse::<GameRecord>().is_err());
+    let unfinished = DRAWN_GAME.replace("[Result \"X 0 : O 0\"]", "[Result \"*\"]");
+    assert!(unfinished.parse::<GameRecord>().is_err());
+
+    let interrupted = "[Result \"X 1 : O 0\"]\n\nX1 a1-b1 O2 b2-c3"
+        .parse::<GameRecord>()
+        .unwrap();
+    assert_eq!(interrupted.result(), Some(Score::new(2, 0)));
+}
+
+#[test]
+fn from_moves_history() {
+    let game_record = DRAWN_GAME.parse::<GameRecord>().unwrap();
+    let mut moves_history = MovesHistory::new(Uuid::nil(), *game_record.game_configuration());
+    for (player_move, player_symbol) in game_record.moves() {
+        moves_history.add_move(player_move, Duration::ZERO, player_symbol);
+    }
+    assert_eq!(GameRecord::try_from(&moves_history).unwrap(), game_record);
+}
+
+#[test]
+fn unfinished_game_with_defaults() {
+    let game_record = "[Size \"4\"]\n[Event \"Casual\"]\n\nX1 a1-d4 O2 b2-c3"
+        .parse::<GameRecord>()
+        .unwrap();
+    assert_eq!(game_record.game_configuration().size(), 4);
+    assert_eq!(game_record.game_configuration().win_length(), 4);
+    assert_eq!(game_record.result(), None);
+    assert!(game_record.to_string().contains("[Result \"*\"]"));
+}
+
+#[test]
+fn move_not_fitting_game() {
+    assert!("X2 a1-b1".parse::<GameRecord>().is_err());
+    assert!("X1 a1-b1 X2 a1-c1".parse::<GameRecord>().is_err());
+    assert!("X1 a1-b1 O2 a1-b1 a1=X2".parse::<GameRecord>().is_err());
+    assert!("X1 a1-b1 O2".parse::<GameRecord>().is_err());
+}
+
+#[test]
+fn invalid_size() {
+    assert!("[Size \"1\"]".parse::<GameRecord>().is_err());
+    let configuration = GameConfiguration::new(
+        1,
+        None,
+        Duration::ZERO,
+        Duration::ZERO,
+        Some(Player::Player1),
+    );
+    assert_eq!(
+        configuration.unwrap_err().current_context(),
+        &GameError::InvalidSize
+    );
+}
+
+#[test]
+fn invalid_win_length() {
+    assert!("[WinLength \"0\"]".parse::<GameRecord>().is_err());
+    assert!("[Size \"3\"]\n[WinLength \"4\"]"
+        .parse::<GameRecord>()
+        .is_err());
+    let configuration = GameConfiguration::new(
+        4,
+        Some(5),
+        Duration::ZERO,
+        Duration::ZERO,
+        Some(Player::Player1),
+    );
+    assert_eq!(
+        configuration.unwrap_err().current_context(),
+        &GameError::InvalidWinLength
+    );
+}
+
+#[test]
+fn misere_game() {
+    let game_record = "[Rules \"Misere\"]\n\nX1 a1-a2 O2 a1-a2 a1=X1 X3 b1-b2 O4 b1-b2 b1=X3 X5 c1-c3 O6 c1-c3 c1=X5"
+        .parse::<GameRecord>()
+        .unwrap();
+    assert_eq!(game_record.game_configuration().rule_set(), RuleSet::Misere);
+    let mut moves_history = MovesHistory::new(Uuid::nil(), *game_record.game_configuration());
+    for (player_move, player_symbol) in game_record.moves() {
+        moves_history.add_move(player_move, Duration::ZERO, player_symbol);
+    }
+    let game_record = GameRecord::try_from(&moves_history).unwrap();
+    assert_eq!(game_record.result(), Some(Score::new(0, 2)));
+    let text = game_record.to_string();
+    assert!(text.contains("[Rules \"Misere\"]"));
+    assert_eq!(text.parse::<GameRecord>().unwrap(), game_record);
+}
+
+#[test]
+fn random_collapse_chooser() {
+    let game_configuration =
+        GameConfiguration::default().with_collapse_chooser(CollapseChooser::Random { seed: 42 });
+    let mut game = game_configuration.create_game().unwrap();
+    let mut moves = Vec::new();
+    let mut result = None;
+    while let Some(player_move) = game.legal_moves().next() {
+        let player_symbol = game.get_status().get_player_turn();
+        if let GameResult::GameEnd(score, _) = game.player_move(player_move, player_symbol).unwrap()
+        {
+            result = Some(score);
+        }
+        moves.push((player_move, player_symbol));
+    }
+    let game_record = GameRecord::new(game_configuration, &moves, result).unwrap();
+    let text = game_record.to_string();
+    assert!(text.contains("[CollapseChooser \"Random 42\"]"));
+    assert_eq!(text.parse::<GameRecord>().unwrap(), game_record);
+    assert!("[CollapseChooser \"Random x\"]"
+        .parse::<GameRecord>()
+        .is_err());
+}
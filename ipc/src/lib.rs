@@ -3,6 +3,7 @@
 pub mod from_server;
 pub mod game_configuration;
 pub mod game_history;
+pub mod game_record;
 pub mod moves_history;
 pub mod player_assignment;
 pub mod player_enum;
@@ -1,3 +1,4 @@
+use crate::game_configuration::GameConfiguration;
 use engine::player_move::Move;
 use engine::player_symbol::PlayerSymbol;
 use serde::{Deserialize, Serialize};
@@ -7,7 +8,7 @@ use uuid::Uuid;
 #[derive(Serialize, Deserialize, PartialEq, Debug)]
 pub struct MovesHistory {
     game_uuid: Uuid,
-    board_size: usize,
+    game_configuration: GameConfiguration,
     moves: Vec<(Move, PlayerSymbol)>,
     durations: Vec<Duration>,
 }
@@ -19,10 +20,11 @@ impl MovesHistory {
     }
 
     #[must_use]
-    pub fn new(game_uuid: Uuid, size: usize) -> Self {
+    pub fn new(game_uuid: Uuid, game_configuration: GameConfiguration) -> Self {
+        let size = game_configuration.size();
         Self {
             game_uuid,
-            board_size: size,
+            game_configuration,
             moves: Vec::with_capacity(size + size / 2),
             durations: Vec::with_capacity(size + size / 2),
         }
@@ -33,8 +35,8 @@ impl MovesHistory {
         self.game_uuid
     }
 
-    pub(super) fn board_size(&self) -> usize {
-        self.board_size
+    pub(super) fn game_configuration(&self) -> &GameConfiguration {
+        &self.game_configuration
     }
 
     pub(super) fn moves(&self) -> &Vec<(Move, PlayerSymbol)> {
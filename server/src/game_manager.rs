@@ -25,13 +25,15 @@ impl GameManager {
     pub fn new(uuid: Uuid, game_configuration: &GameConfiguration) -> Self {
         Self {
             uuid,
-            game: Game::new(game_configuration.size()),
+            game: game_configuration
+                .create_game()
+                .expect("Configuration should be checked"),
             timer: Timer::new(
                 game_configuration.based_time(),
                 game_configuration.increment(),
             ),
             player_assignment: PlayerAssignment::new(*game_configuration.first_player()),
-            history: MovesHistory::new(uuid, game_configuration.size()),
+            history: MovesHistory::new(uuid, *game_configuration),
         }
     }
 
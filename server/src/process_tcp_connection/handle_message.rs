@@ -2,8 +2,9 @@ use crate::game_manager::GameManager;
 use crate::game_repository::GameRepository;
 use crate::process_tcp_connection::io;
 use crate::server_error::ServerError;
+use engine::board::board_error::BoardErrorKind;
 use engine::game::game_error;
-use engine::game::game_result::GameResult;
+use engine::game::game_result::{EndReason, GameResult};
 use engine::player_move::Move;
 use engine::player_symbol::PlayerSymbol;
 use error_stack::{bail, Result};
@@ -36,6 +37,10 @@ pub async fn handle_create_game<Repository: GameRepository + ?Sized, Writer: Asy
         info!("{game_configuration:?}");
         let game_manager_created =
             create_new_game(game_configuration, game_repository.clone()).await;
+        if game_manager_created.is_none() {
+            io::write_message(&mut writer, &FromServer::GameNotCreated).await?;
+            return Ok(());
+        }
         *game_manager = game_manager_created;
         let uuid = game_manager
             .as_ref()
@@ -97,8 +102,12 @@ pub async fn handle_make_move<Writer: AsyncWrite + Unpin>(
                 ),
             )
             .await?;
-            if let GameResult::GameEnd(winner) = result {
-                io::write_message(&mut writer, &FromServer::GameEnded(*winner)).await?;
+            if let GameResult::GameEnd(score, reason) = result {
+                io::write_message(
+                    &mut writer,
+                    &FromServer::GameEnded(score.winner(), reason.clone()),
+                )
+                .await?;
                 game_manager
                     .as_mut()
                     .expect("Game manager should exist")
@@ -108,7 +117,13 @@ pub async fn handle_make_move<Writer: AsyncWrite + Unpin>(
             io::write_message(&mut writer, &FromServer::MoveOk(result.into())).await?;
         }
         Err(err) => {
-            if err.current_context() == &game_error::GameError::MakingMoveError {
+            // Mistakes in the move leave the game untouched, only a broken
+            // board or rules that never end the game can't be played on
+            if matches!(
+                err.current_context(),
+                game_error::GameError::MakingMoveError(BoardErrorKind::Inconsistent)
+                    | game_error::GameError::MissingGameEnd
+            ) {
                 io::write_message(&mut writer, &FromServer::GameCrash).await?;
                 bail!(err.change_context(ServerError::GameError))
             }
@@ -132,7 +147,11 @@ pub async fn handle_end_game<Writer: AsyncWrite + Unpin>(
         .as_mut()
         .expect("Game manager should exist")
         .set_winner(player);
-    io::write_message(&mut writer, &FromServer::GameEnded(winner)).await?;
+    io::write_message(
+        &mut writer,
+        &FromServer::GameEnded(winner, EndReason::Interrupted),
+    )
+    .await?;
     game_manager
         .as_mut()
         .expect("Game manager should exist")
@@ -144,6 +163,11 @@ async fn create_new_game<Repository: GameRepository + ?Sized>(
     game_configuration: GameConfiguration,
     game_repository: Arc<Mutex<Box<Repository>>>,
 ) -> Option<GameManager> {
+    // An invalid configuration doesn't take a game id
+    if let Err(err) = game_configuration.create_game() {
+        error!("{err:?}");
+        return None;
+    }
     #[cfg(test)]
     let mut uuid = Uuid::nil();
     #[cfg(not(test))]
@@ -1,6 +1,14 @@
 use super::*;
 use crate::game_repository::local_repository::LocalRepository;
+use engine::board::board_error::BoardErrorKind;
+use engine::field_coordinate::FieldCoordinate;
+use engine::game::game_result::EndReason;
+use engine::player_move::Move;
+use engine::player_symbol::PlayerSymbol;
+use engine::rules::CollapseChooser;
 use ipc::from_server::board_ipc::Board;
+use ipc::from_server::game_error_ipc::GameError;
+use ipc::from_server::game_result_ipc::GameResult;
 use ipc::from_server::FromServer;
 use ipc::game_configuration::GameConfiguration;
 use uuid::Uuid;
@@ -35,6 +43,27 @@ async fn create_game_two_times() {
     let () = process(reader, writer, repository).await;
 }
 
+#[tokio::test]
+async fn create_game_invalid_win_length() {
+    // Configurations received from clients aren't built through
+    // GameConfiguration::new, the win length is set to zero by its name
+    let mut value = serde_json::to_value(GameConfiguration::default()).unwrap();
+    value["win_length"] = 0.into();
+    let game_configuration: GameConfiguration = serde_json::from_value(value).unwrap();
+    assert_eq!(game_configuration.win_length(), 0);
+    let repository = Arc::new(Mutex::new(Box::new(LocalRepository::new())));
+    let reader = tokio_test::io::Builder::new()
+        .read(&bincode::serialize(&ToServer::CreateGame(game_configuration)).unwrap())
+        .read(&bincode::serialize(&ToServer::CreateGame(GameConfiguration::default())).unwrap())
+        .build();
+    let writer = tokio_test::io::Builder::new()
+        .write(&bincode::serialize(&FromServer::GameNotCreated).unwrap())
+        .write(&bincode::serialize(&FromServer::GameCreated(Uuid::nil())).unwrap())
+        .write(&bincode::serialize(&FromServer::Board(Board::default())).unwrap())
+        .build();
+    let () = process(reader, writer, repository).await;
+}
+
 #[tokio::test]
 async fn ping() {
     let repository = Arc::new(Mutex::new(Box::new(LocalRepository::new())));
@@ -46,3 +75,152 @@ async fn ping() {
         .build();
     let () = process(reader, writer, repository).await;
 }
+
+#[tokio::test]
+async fn classical_move_not_allowed() {
+    let game_configuration = GameConfiguration::default();
+    let repository = Arc::new(Mutex::new(Box::new(LocalRepository::new())));
+    let player_move = Move::Classical {
+        field: FieldCoordinate { x: 0, y: 0 },
+    };
+    let reader = tokio_test::io::Builder::new()
+        .read(&bincode::serialize(&ToServer::CreateGame(game_configuration)).unwrap())
+        .read(&bincode::serialize(&ToServer::MakeMove((PlayerSymbol::X, player_move))).unwrap())
+        .build();
+    let writer = tokio_test::io::Builder::new()
+        .write(&bincode::serialize(&FromServer::GameCreated(Uuid::nil())).unwrap())
+        .write(&bincode::serialize(&FromServer::Board(Board::default())).unwrap())
+        .write(&bincode::serialize(&FromServer::MoveErr(GameError::MoveTypeError)).unwrap())
+        .build();
+    let () = process(reader, writer, repository).await;
+}
+
+#[tokio::test]
+async fn wrong_coordinates_keep_game() {
+    let game_configuration = GameConfiguration::default();
+    let repository = Arc::new(Mutex::new(Box::new(LocalRepository::new())));
+    let same_field = Move::Mark {
+        field1: FieldCoordinate { x: 0, y: 0 },
+        field2: FieldCoordinate { x: 0, y: 0 },
+    };
+    let out_of_board = Move::Mark {
+        field1: FieldCoordinate { x: 0, y: 0 },
+        field2: FieldCoordinate { x: 0, y: 9 },
+    };
+    let reader = tokio_test::io::Builder::new()
+        .read(&bincode::serialize(&ToServer::CreateGame(game_configuration)).unwrap())
+        .read(&bincode::serialize(&ToServer::MakeMove((PlayerSymbol::X, same_field))).unwrap())
+        .read(&bincode::serialize(&ToServer::MakeMove((PlayerSymbol::X, out_of_board))).unwrap())
+        .read(&bincode::serialize(&ToServer::PING).unwrap())
+        .build();
+    let writer = tokio_test::io::Builder::new()
+        .write(&bincode::serialize(&FromServer::GameCreated(Uuid::nil())).unwrap())
+        .write(&bincode::serialize(&FromServer::Board(Board::default())).unwrap())
+        .write(
+            &bincode::serialize(&FromServer::MoveErr(GameError::MakingMoveError(
+                BoardErrorKind::SameCoordinates,
+            )))
+            .unwrap(),
+        )
+        .write(
+            &bincode::serialize(&FromServer::MoveErr(GameError::MakingMoveError(
+                BoardErrorKind::OutOfBoard,
+            )))
+            .unwrap(),
+        )
+        .write(&bincode::serialize(&FromServer::PONG).unwrap())
+        .build();
+    let () = process(reader, writer, repository).await;
+}
+
+#[tokio::test]
+async fn collapse_by_cycle_creator() {
+    let game_configuration =
+        GameConfiguration::default().with_collapse_chooser(CollapseChooser::Creator);
+    let repository = Arc::new(Mutex::new(Box::new(LocalRepository::new())));
+    let mark = Move::Mark {
+        field1: FieldCoordinate { x: 0, y: 0 },
+        field2: FieldCoordinate { x: 1, y: 0 },
+    };
+    let collapse = Move::Collapse {
+        field: FieldCoordinate { x: 0, y: 0 },
+        index: 0,
+    };
+    let mut game = game_configuration.create_game().unwrap();
+    let mut expected_moves = Vec::new();
+    for (player_symbol, player_move) in [
+        (PlayerSymbol::X, mark),
+        (PlayerSymbol::O, mark),
+        (PlayerSymbol::O, collapse),
+    ] {
+        let result = game.player_move(player_move, player_symbol).unwrap();
+        expected_moves.push((
+            bincode::serialize(&FromServer::Board(Board::from(game.get_board()))).unwrap(),
+            bincode::serialize(&FromServer::MoveOk(GameResult::from(&result))).unwrap(),
+        ));
+    }
+    let reader = tokio_test::io::Builder::new()
+        .read(&bincode::serialize(&ToServer::CreateGame(game_configuration)).unwrap())
+        .read(&bincode::serialize(&ToServer::MakeMove((PlayerSymbol::X, mark))).unwrap())
+        .read(&bincode::serialize(&ToServer::MakeMove((PlayerSymbol::O, mark))).unwrap())
+        .read(&bincode::serialize(&ToServer::MakeMove((PlayerSymbol::X, collapse))).unwrap())
+        .read(&bincode::serialize(&ToServer::MakeMove((PlayerSymbol::O, collapse))).unwrap())
+        .build();
+    let mut writer = tokio_test::io::Builder::new();
+    writer
+        .write(&bincode::serialize(&FromServer::GameCreated(Uuid::nil())).unwrap())
+        .write(&bincode::serialize(&FromServer::Board(Board::default())).unwrap());
+    for (board, result) in &expected_moves[..2] {
+        writer.write(board).write(result);
+    }
+    // The opponent of the cycle creator isn't the one to collapse
+    writer.write(&bincode::serialize(&FromServer::MoveErr(GameError::PlayerTurnError)).unwrap());
+    let (board, result) = &expected_moves[2];
+    writer.write(board).write(result);
+    let () = process(reader, writer.build(), repository).await;
+}
+
+#[tokio::test]
+async fn game_end_with_lines() {
+    let game_configuration = GameConfiguration::default();
+    let repository = Arc::new(Mutex::new(Box::new(LocalRepository::new())));
+    let mark = |(x1, y1), (x2, y2)| Move::Mark {
+        field1: FieldCoordinate { x: x1, y: y1 },
+        field2: FieldCoordinate { x: x2, y: y2 },
+    };
+    let collapse = |(x, y), index| Move::Collapse {
+        field: FieldCoordinate { x, y },
+        index,
+    };
+    // X collapses the top row in the last move
+    let mut moves = Vec::new();
+    for (column, row, turn) in [(0, 1, 0), (1, 1, 2), (2, 2, 4)] {
+        moves.push((PlayerSymbol::X, mark((column, 0), (column, row))));
+        moves.push((PlayerSymbol::O, mark((column, 0), (column, row))));
+        moves.push((PlayerSymbol::X, collapse((column, 0), turn)));
+    }
+    let mut game = game_configuration.create_game().unwrap();
+    let mut reader = tokio_test::io::Builder::new();
+    reader.read(&bincode::serialize(&ToServer::CreateGame(game_configuration)).unwrap());
+    let mut writer = tokio_test::io::Builder::new();
+    writer
+        .write(&bincode::serialize(&FromServer::GameCreated(Uuid::nil())).unwrap())
+        .write(&bincode::serialize(&FromServer::Board(Board::default())).unwrap());
+    for (player_symbol, player_move) in moves {
+        let result = game.player_move(player_move, player_symbol).unwrap();
+        reader
+            .read(&bincode::serialize(&ToServer::MakeMove((player_symbol, player_move))).unwrap());
+        writer
+            .write(&bincode::serialize(&FromServer::Board(Board::from(game.get_board()))).unwrap());
+        let message = match result {
+            engine::game::game_result::GameResult::GameEnd(score, reason) => {
+                assert!(matches!(reason, EndReason::Lines(_)));
+                FromServer::GameEnded(score.winner(), reason)
+            }
+            result => FromServer::MoveOk(GameResult::from(&result)),
+        };
+        writer.write(&bincode::serialize(&message).unwrap());
+    }
+    assert!(game.get_status().is_game_end());
+    let () = process(reader.build(), writer.build(), repository).await;
+}
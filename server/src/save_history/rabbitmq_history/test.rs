@@ -1,5 +1,6 @@
 use super::*;
 use futures::StreamExt;
+use ipc::game_configuration::GameConfiguration;
 use ipc::rabbitmq::CONSUMER_SERVER;
 use lapin::options::BasicConsumeOptions;
 use lapin::{options::*, types::FieldTable, Connection, ConnectionProperties};
@@ -81,7 +82,7 @@ async fn handle_get_history(connection_string: &str, barrier: Arc<Barrier>) {
         if let Ok(delivery) = delivery {
             let uuid: Uuid = bincode::deserialize(&delivery.data).unwrap();
 
-            let moves_history = MovesHistory::new(uuid, 3);
+            let moves_history = MovesHistory::new(uuid, GameConfiguration::default());
             let game_history = GameHistory::try_from(&moves_history).unwrap();
             let encode = bincode::serialize(&game_history).unwrap();
 
@@ -124,7 +125,7 @@ async fn send_empty_move_history() {
     let rabbitmq_history = RabbitmqHistory::new(&rabbitmq_connection_string)
         .await
         .unwrap();
-    let move_history = MovesHistory::new(UUID, 3);
+    let move_history = MovesHistory::new(UUID, GameConfiguration::default());
     rabbitmq_history.save_game(&move_history).await.unwrap();
 
     let body = consume_message(&rabbitmq_connection_string).await;
@@ -156,7 +157,7 @@ async fn get_empty_game_history() {
         handle_get_history(&rabbitmq_connection_string, barrier_clone).await;
     });
 
-    let move_history = MovesHistory::new(UUID, 3);
+    let move_history = MovesHistory::new(UUID, GameConfiguration::default());
     let game_history = GameHistory::try_from(&move_history).unwrap();
 
     barrier.wait().await;